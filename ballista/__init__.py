@@ -16,27 +16,46 @@
 # under the License.
 
 from abc import ABCMeta, abstractmethod
-from typing import List
+from typing import Iterable, List, Optional, Union
 
 import pyarrow as pa
 
 from ._internal import (
     AggregateUDF,
+    BallistaConfig,
     BallistaContext,
     DataFrame,
+    SessionConfig,
     SessionContext,
+    ExecutionPlan,
     Expression,
+    JobHandle,
+    LogicalPlan,
+    Metric,
+    MetricsSet,
+    RecordBatchStream,
     ScalarUDF,
+    WindowFrame,
 )
 
 
 __all__ = [
+    "BallistaConfig",
     "BallistaContext",
     "DataFrame",
+    "SessionConfig",
     "SessionContext",
+    "ExecutionPlan",
     "Expression",
+    "JobHandle",
+    "LogicalPlan",
+    "Metric",
+    "MetricsSet",
+    "RecordBatchStream",
     "AggregateUDF",
     "ScalarUDF",
+    "TableProvider",
+    "WindowFrame",
     "column",
     "literal",
 ]
@@ -60,6 +79,35 @@ class Accumulator(metaclass=ABCMeta):
         pass
 
 
+class TableProvider(metaclass=ABCMeta):
+    """
+    Interface of a table implemented in Python, to be registered with
+    `SessionContext.register_table_provider`.
+    """
+
+    @abstractmethod
+    def schema(self) -> pa.Schema:
+        pass
+
+    @abstractmethod
+    def scan(
+        self,
+        projection: Optional[List[str]],
+        filters: List[Expression],
+        limit: Optional[int],
+    ) -> Union[pa.Table, Iterable[pa.RecordBatch]]:
+        """
+        Returns the rows of the table, restricted to the `projection` columns
+        if given. `filters` only contain the expressions accepted by
+        `supports_filter_pushdown`, and they are applied again on the
+        returned rows, so honoring them is optional.
+        """
+        pass
+
+    def supports_filter_pushdown(self, filter: Expression) -> bool:
+        return False
+
+
 def column(value):
     return Expression.column(value)
 
@@ -68,8 +116,6 @@ col = column
 
 
 def literal(value):
-    if not isinstance(value, pa.Scalar):
-        value = pa.scalar(value)
     return Expression.literal(value)
 
 
@@ -0,0 +1,54 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+from typing import Iterable
+
+import pyarrow as pa
+
+from ._internal import flight
+
+
+def read_endpoints(endpoints: Iterable["flight.FlightEndpoint"]) -> pa.Table:
+    """
+    Fetches the partitions served by `endpoints`, as returned by
+    `JobHandle.flight_endpoints`, with `pyarrow.flight`.
+    """
+    from pyarrow import flight as pa_flight
+
+    tables = []
+    for endpoint in endpoints:
+        client = pa_flight.connect(endpoint.uri)
+        try:
+            reader = client.do_get(pa_flight.Ticket(endpoint.ticket))
+            tables.append(reader.read_all())
+        finally:
+            client.close()
+    return pa.concat_tables(tables)
+
+
+def collect_flight(ctx, df, poll_interval: float = 0.1) -> pa.Table:
+    """
+    Submits `df` to the cluster of the `BallistaContext` `ctx`, waits for it
+    and fetches the result over Arrow Flight. This isn't a `DataFrame`
+    method, as a `DataFrame` doesn't know the scheduler to submit it to.
+    """
+    job = ctx.submit(df)
+    return read_endpoints(job.flight_endpoints(poll_interval))
+
+
+def __getattr__(name):
+    return getattr(flight, name)
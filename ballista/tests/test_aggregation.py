@@ -18,7 +18,7 @@
 import pyarrow as pa
 import pytest
 
-from ballista import SessionContext, column
+from ballista import SessionContext, column, literal
 from ballista import functions as f
 
 
@@ -46,3 +46,71 @@ def test_built_in_aggregation(df):
     assert result.column(1) == pa.array([1])
     assert result.column(2) == pa.array([3], type=pa.int64())
     assert result.column(3) == pa.array([2], type=pa.uint64())
+
+
+def test_statistical_aggregation(df):
+    col_a = column("a")
+    col_b = column("b")
+    df = df.aggregate(
+        [],
+        [
+            f.stddev(col_a),
+            f.stddev_pop(col_a),
+            f.variance(col_a),
+            f.var_pop(col_a),
+            f.covar(col_a, col_b),
+            f.covar_pop(col_a, col_b),
+            f.corr(col_a, col_a),
+            f.median(col_b),
+            f.approx_median(col_b),
+            f.approx_percentile_cont(col_a, literal(0.5)),
+            f.approx_percentile_cont_with_weight(col_a, col_b, literal(0.5)),
+            f.covariance(col_b, col_a),
+        ],
+    )
+    values = [c[0].as_py() for c in df.collect()[0].columns]
+    assert values[0] == pytest.approx(1.0)
+    assert values[1] == pytest.approx((2 / 3) ** 0.5)
+    assert values[2] == pytest.approx(1.0)
+    assert values[3] == pytest.approx(2 / 3)
+    assert values[4] == pytest.approx(1.0)
+    assert values[5] == pytest.approx(2 / 3)
+    assert values[6] == pytest.approx(1.0)
+    assert values[7] == 4
+    assert values[8] == 4
+    assert values[9] == 2
+    assert 1 <= values[10] <= 3
+    assert values[11] == pytest.approx(1.0)
+
+
+def test_array_agg(df):
+    df = df.aggregate([column("b")], [f.array_agg(column("a"))]).sort(
+        column("b").sort()
+    )
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([4, 6])
+    assert sorted(result.column(1)[0].as_py()) == [1, 2]
+    assert result.column(1)[1].as_py() == [3]
+
+
+def test_aggregation_filter(df):
+    col_a = column("a")
+    df = df.aggregate(
+        [],
+        [
+            f.sum(col_a, filter=column("b") > literal(4)),
+            f.count(col_a, filter=col_a > literal(1)),
+        ],
+    )
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([3])
+    assert result.column(1) == pa.array([2], type=pa.int64())
+
+
+def test_grouping(df):
+    df = df.aggregate([column("b")], [f.grouping(column("b"))])
+    assert "GROUPING(" in df.logical_plan().display_indent()
+
+    # DataFusion 17 has no physical implementation of GROUPING yet
+    with pytest.raises(Exception, match="not yet implemented"):
+        df.collect()
@@ -70,3 +70,23 @@ def test_basic(ctx, database):
             pa.field("float", pa.float64(), nullable=True),
         ]
     )
+
+
+def test_table_exists(ctx, database):
+    assert ctx.table_exists("csv")
+    assert not ctx.table_exists("non-existent")
+
+    public = ctx.catalog().database()
+    assert public.table_exists("csv1")
+    assert not public.table_exists("non-existent")
+
+    ctx.deregister_table("csv")
+    assert not ctx.table_exists("csv")
+    assert public.names() == {"csv1", "csv2"}
+
+
+def test_table_exists_unknown_schema(ctx, database):
+    assert not ctx.table_exists("missing.csv")
+    assert not ctx.table_exists("missing.public.csv")
+    assert not ctx.table_exists("datafusion.missing.csv")
+    assert ctx.table_exists("datafusion.public.csv")
@@ -18,7 +18,12 @@
 import pyarrow as pa
 import pytest
 
-from ballista import SessionContext
+from ballista import (
+    BallistaConfig,
+    BallistaContext,
+    SessionConfig,
+    SessionContext,
+)
 
 
 @pytest.fixture
@@ -61,3 +66,125 @@ def test_create_dataframe_registers_unique_table_name(ctx):
     # only hexadecimal numbers
     for c in tables[0][1:]:
         assert c in "0123456789abcdef"
+
+
+def test_create_context_with_config():
+    config = (
+        SessionConfig()
+        .with_target_partitions(4)
+        .with_batch_size(1024)
+        .with_repartition_joins(False)
+        .with_information_schema(True)
+    )
+    assert config.target_partitions == 4
+    assert config.batch_size == 1024
+
+    ctx = SessionContext(config=config)
+    batches = ctx.sql("SELECT * FROM information_schema.tables").collect()
+    assert len(batches) > 0
+
+
+def test_session_config_set():
+    config = SessionConfig().set("datafusion.execution.batch_size", "512")
+    assert config.batch_size == 512
+
+    with pytest.raises(ValueError):
+        SessionConfig().set("datafusion.missing.option", "1")
+    with pytest.raises(ValueError):
+        SessionConfig().set("datafusion.execution.batch_size", "many")
+
+
+def test_config_takes_precedence_over_kwargs():
+    config = SessionConfig().with_information_schema(True)
+    ctx = SessionContext(information_schema=False, config=config)
+    batches = ctx.sql("SELECT * FROM information_schema.tables").collect()
+    assert len(batches) > 0
+
+
+def test_session_config_is_immutable():
+    config = SessionConfig().with_target_partitions(2)
+    config.with_target_partitions(3)
+    assert config.target_partitions == 2
+
+
+def test_ballista_config():
+    config = (
+        BallistaConfig()
+        .with_shuffle_partitions(32)
+        .with_batch_size(4096)
+        .set("ballista.job.name", "test")
+    )
+    settings = config.settings()
+    assert settings["ballista.shuffle.partitions"] == "32"
+    assert settings["ballista.batch.size"] == "4096"
+    assert settings["ballista.job.name"] == "test"
+    assert settings["ballista.with_information_schema"] == "true"
+
+
+def test_ballista_remote_invalid_url():
+    with pytest.raises(ValueError, match="Unsupported scheduler url scheme"):
+        BallistaContext.remote("http://localhost:50050")
+
+    with pytest.raises(ValueError, match="Invalid scheduler port"):
+        BallistaContext.remote("df://localhost:port")
+
+    with pytest.raises(ValueError, match="TLS connections"):
+        BallistaContext.remote("grpcs://localhost:50050")
+
+    with pytest.raises(ValueError, match="connect_timeout"):
+        BallistaContext.remote("df://localhost:50050", connect_timeout=-1)
+
+    with pytest.raises(ValueError, match="connect_timeout"):
+        BallistaContext.remote("df://localhost:50050", connect_timeout=0)
+
+
+def test_ballista_remote_connect_timeout():
+    # a non-routable address, so that connecting hangs until the timeout
+    with pytest.raises(Exception, match="(?i)timed out|connect"):
+        BallistaContext.remote("df://10.255.255.1:50050", connect_timeout=0.5)
+
+
+def test_from_arrow_table(ctx):
+    table = pa.table({"a": [1, 2, 3], "b": [4, 5, 6]})
+
+    df = ctx.from_arrow_table(table)
+    assert df.collect()[0].column(0) == pa.array([1, 2, 3])
+
+    reader = pa.RecordBatchReader.from_batches(
+        table.schema, table.to_batches()
+    )
+    df = ctx.from_arrow_table(reader, name="t")
+    assert "t" in ctx.tables()
+    assert df.collect()[0].column(1) == pa.array([4, 5, 6])
+
+
+def test_from_pylist(ctx):
+    df = ctx.from_pylist([{"a": 1, "b": "x"}, {"a": 2, "b": "y"}], name="t")
+
+    assert "t" in ctx.tables()
+    result = ctx.sql("SELECT a, b FROM t").collect()[0]
+    assert result.column(0) == pa.array([1, 2])
+    assert result.column(1) == pa.array(["x", "y"])
+
+
+def test_from_pydict(ctx):
+    df = ctx.from_pydict({"a": [1, 2], "b": ["x", "y"]})
+
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([1, 2])
+    assert result.column(1) == pa.array(["x", "y"])
+
+
+def test_from_pandas(ctx):
+    pd = pytest.importorskip("pandas")
+
+    df = ctx.from_pandas(pd.DataFrame({"a": [1, 2, 3]}))
+    assert df.collect()[0].column(0) == pa.array([1, 2, 3])
+
+
+def test_from_empty_arrow_table(ctx):
+    table = pa.table({"a": pa.array([], pa.int64())})
+
+    df = ctx.from_arrow_table(table)
+    assert df.schema() == table.schema
+    assert sum(batch.num_rows for batch in df.collect()) == 0
@@ -15,11 +15,22 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import asyncio
+
 import pyarrow as pa
+import pyarrow.csv
+import pyarrow.parquet as pq
 import pytest
 
 from ballista import functions as f
-from ballista import DataFrame, SessionContext, column, literal, udf
+from ballista import (
+    DataFrame,
+    SessionContext,
+    WindowFrame,
+    column,
+    literal,
+    udf,
+)
 
 
 @pytest.fixture
@@ -62,6 +73,60 @@ def test_explain_string(df):
     assert "physical_plan" in explain
 
 
+def test_logical_plan(df):
+    df = df.select((column("a") + column("b")).alias("c")).filter(
+        column("c") > literal(5)
+    )
+
+    plan = df.logical_plan()
+    assert plan.display().startswith("Filter:")
+    assert "Projection:" in plan.display_indent()
+    assert "digraph" in plan.display_graphviz()
+
+    inputs = plan.inputs()
+    assert len(inputs) == 1
+    assert inputs[0].display().startswith("Projection:")
+
+    optimized = df.optimized_logical_plan()
+    assert "Projection:" in optimized.display_indent()
+    assert df.to_pydict() == {"c": [7, 9]}
+
+
+def test_execution_plan(df):
+    df = df.select(column("a") + column("b"))
+
+    plan = df.execution_plan()
+    assert "ProjectionExec" in plan.display_indent()
+    assert plan.display().startswith("ProjectionExec")
+    assert plan.partition_count >= 1
+    assert all(isinstance(child.display(), str) for child in plan.inputs())
+
+
+def test_collect_with_metrics(df):
+    df = df.select(column("a") + column("b"))
+
+    batches, metrics = df.collect_with_metrics()
+    assert batches[0].column(0) == pa.array([5, 7, 9])
+
+    assert len(metrics) > 0
+    assert metrics.root_value("output_rows") == 3
+
+    projection = metrics.metrics("ProjectionExec")
+    assert {m.name for m in projection} >= {"output_rows", "elapsed_compute"}
+    assert all(m.depth == 0 for m in projection)
+
+    dicts = metrics.to_dicts()
+    assert len(dicts) == len(list(metrics))
+    assert set(dicts[0]) == {
+        "operator",
+        "depth",
+        "name",
+        "value",
+        "partition",
+        "labels",
+    }
+
+
 def test_select(df):
     df = df.select(
         column("a") + column("b"),
@@ -107,6 +172,237 @@ def test_limit(df):
     assert len(result.column(1)) == 1
 
 
+def test_execute_stream(df):
+    df = df.select(column("a") + column("b"))
+
+    stream = df.execute_stream()
+    assert stream.schema() == df.schema()
+
+    batches = list(stream)
+    assert len(batches) == 1
+    assert batches[0].column(0) == pa.array([5, 7, 9])
+
+    # the stream is exhausted, and stays so
+    assert stream.next() is None
+    assert stream.next() is None
+    assert list(stream) == []
+
+
+def test_execute_stream_async(df):
+    df = df.select(column("a") + column("b"))
+
+    async def collect():
+        return [batch async for batch in df.execute_stream()]
+
+    batches = asyncio.run(collect())
+    assert len(batches) == 1
+    assert batches[0].column(0) == pa.array([5, 7, 9])
+
+
+def test_count(df):
+    assert df.count() == 3
+    assert df.filter(column("a") > literal(1)).count() == 2
+
+
+def test_head_and_tail(df):
+    df = df.sort(column("a").sort())
+
+    head = df.head(2)
+    assert isinstance(head, pa.Table)
+    assert head.column("a") == pa.chunked_array([[1, 2]])
+
+    tail = df.tail(2)
+    assert tail.column("a") == pa.chunked_array([[2, 3]])
+
+    assert df.head().num_rows == 3
+    assert df.tail(10).num_rows == 3
+
+
+def test_tail_across_batches():
+    ctx = SessionContext()
+    batches = [
+        pa.RecordBatch.from_arrays([pa.array(values)], names=["a"])
+        for values in [[1, 2, 3], [4, 5]]
+    ]
+    df = ctx.create_dataframe([batches])
+
+    assert df.tail(3).column("a").to_pylist() == [3, 4, 5]
+    assert df.tail(10).column("a").to_pylist() == [1, 2, 3, 4, 5]
+    assert df.tail(0).num_rows == 0
+    assert df.tail(0).schema.names == ["a"]
+
+
+def test_head_of_empty_result(df):
+    head = df.filter(column("a") > literal(10)).head()
+    assert head.num_rows == 0
+    assert head.schema.names == ["a", "b"]
+
+
+def test_conversions(df):
+    table = df.to_arrow_table()
+    assert table.to_pydict() == {"a": [1, 2, 3], "b": [4, 5, 6]}
+    assert df.to_pydict() == {"a": [1, 2, 3], "b": [4, 5, 6]}
+
+    pandas_df = df.to_pandas()
+    assert list(pandas_df.columns) == ["a", "b"]
+    assert pandas_df["a"].tolist() == [1, 2, 3]
+
+
+def test_to_polars(df):
+    pytest.importorskip("polars")
+    polars_df = df.to_polars()
+    assert polars_df.shape == (3, 2)
+    assert polars_df["b"].to_list() == [4, 5, 6]
+
+
+def test_repr(df):
+    text = repr(df)
+    assert text.startswith("DataFrame[a: Int64, b: Int64]")
+    assert "| a | b |" in text
+    assert "..." not in text
+
+    html = df._repr_html_()
+    assert html.startswith("<table")
+    assert "<th>a</th><th>b</th>" in html
+    assert "<td>1</td><td>4</td>" in html
+
+
+def test_repr_limits(df):
+    try:
+        DataFrame.set_display_options(max_rows=2, max_width=4)
+        text = repr(df.select(literal("long value").alias("c"), column("a")))
+        assert text.endswith("...")
+        assert "l..." in text
+        assert "| 3 |" not in text
+
+        html = df._repr_html_()
+        assert html.count("<tr>") == 4
+        assert "<td>...</td>" in html
+    finally:
+        DataFrame.set_display_options()
+
+
+def test_write_parquet(df, tmp_path):
+    path = tmp_path / "out"
+    df.write_parquet(str(path), compression="snappy", row_group_size=2)
+
+    table = pq.read_table(path)
+    assert table.to_pydict() == {"a": [1, 2, 3], "b": [4, 5, 6]}
+
+
+def test_write_parquet_unsupported_compression(df, tmp_path):
+    with pytest.raises(Exception, match="Unsupported compression"):
+        df.write_parquet(str(tmp_path / "out"), compression="foo")
+
+
+def test_write_csv(df, tmp_path):
+    path = tmp_path / "out"
+    df.write_csv(str(path))
+
+    table = pa.concat_tables(
+        [pa.csv.read_csv(f) for f in sorted(path.iterdir())]
+    )
+    assert table.to_pydict() == {"a": [1, 2, 3], "b": [4, 5, 6]}
+
+
+def test_write_csv_without_header(df, tmp_path):
+    path = tmp_path / "out"
+    df.write_csv(str(path), header=False)
+
+    content = "".join(f.read_text() for f in sorted(path.iterdir()))
+    assert content.splitlines() == ["1,4", "2,5", "3,6"]
+
+
+def test_write_json(df, tmp_path):
+    path = tmp_path / "out"
+    df.write_json(str(path))
+
+    content = "".join(f.read_text() for f in sorted(path.iterdir()))
+    assert content.splitlines() == [
+        '{"a":1,"b":4}',
+        '{"a":2,"b":5}',
+        '{"a":3,"b":6}',
+    ]
+
+
+def test_repartition(df):
+    df = df.repartition(2)
+
+    assert "RoundRobinBatch(2)" in df.explain_string()
+    table = pa.Table.from_batches(df.collect())
+    assert sorted(table.column("a").to_pylist()) == [1, 2, 3]
+
+
+def test_repartition_by_hash(df):
+    df = df.repartition_by_hash([column("a")], 2)
+
+    assert "Repartition: Hash(" in df.explain_string()
+    table = pa.Table.from_batches(df.collect())
+    assert sorted(table.column("a").to_pylist()) == [1, 2, 3]
+
+
+def test_repartition_into_no_partitions(df):
+    with pytest.raises(ValueError, match="num must be positive"):
+        df.repartition(0)
+
+    with pytest.raises(ValueError, match="num must be positive"):
+        df.repartition_by_hash([column("a")], 0)
+
+
+def test_with_columns(df):
+    df = df.with_columns(
+        c=column("a") + column("b"),
+        a=column("a") * literal(10),
+        d=column("a"),
+    )
+    assert df.schema().names == ["a", "b", "c", "d"]
+
+    # every expression sees the original columns
+    assert df.to_pydict() == {
+        "a": [10, 20, 30],
+        "b": [4, 5, 6],
+        "c": [5, 7, 9],
+        "d": [1, 2, 3],
+    }
+
+
+def test_with_column(df):
+    df = df.with_column("c", column("a") + column("b"))
+    df = df.with_column("a", column("a") * literal(10))
+    assert df.schema().names == ["a", "b", "c"]
+    assert df.to_pydict() == {
+        "a": [10, 20, 30],
+        "b": [4, 5, 6],
+        "c": [5, 7, 9],
+    }
+
+
+def test_with_column_reads_previous_column(df):
+    df = df.with_column("c", column("a") + column("b"))
+    df = df.with_column("c", column("c") * literal(2))
+    df = df.with_column("d", column("c") + literal(1))
+    assert df.to_pydict() == {
+        "a": [1, 2, 3],
+        "b": [4, 5, 6],
+        "c": [10, 14, 18],
+        "d": [11, 15, 19],
+    }
+
+
+def test_chained_with_column_projects_once(df):
+    for i in range(10):
+        df = df.with_column(f"c{i}", column("a") + literal(i))
+
+    plan = df.logical_plan().display_indent()
+    assert plan.count("Projection:") == 1
+    assert df.to_pydict()["c9"] == [10, 11, 12]
+
+
+def test_with_column_unknown_column(df):
+    with pytest.raises(Exception, match="No field named"):
+        df.with_column("c", column("missing"))
+
+
 def test_udf(df):
     # is_null is a pa function over arrays
     is_null = udf(
@@ -122,6 +418,15 @@ def test_udf(df):
     assert result == pa.array([False, False, False])
 
 
+def test_functions_udf(df):
+    is_null = f.udf(lambda x: x.is_null(), [pa.int64()], pa.bool_())
+
+    df = df.select(is_null(column("a")))
+    result = df.collect()[0].column(0)
+
+    assert result == pa.array([False, False, False])
+
+
 def test_join():
     ctx = SessionContext()
 
@@ -145,6 +450,45 @@ def test_join():
     assert table.to_pydict() == expected
 
 
+def test_set_operations():
+    ctx = SessionContext()
+
+    batch = pa.RecordBatch.from_arrays(
+        [pa.array([1, 2, 3]), pa.array([4, 5, 6])],
+        names=["a", "b"],
+    )
+    df = ctx.create_dataframe([[batch]])
+
+    batch = pa.RecordBatch.from_arrays(
+        [pa.array([3, 4]), pa.array([6, 7])],
+        names=["a", "b"],
+    )
+    df1 = ctx.create_dataframe([[batch]])
+
+    def sorted_dict(df):
+        df = df.sort(column("a").sort(ascending=True))
+        return pa.Table.from_batches(df.collect()).to_pydict()
+
+    assert sorted_dict(df.union(df1)) == {
+        "a": [1, 2, 3, 3, 4],
+        "b": [4, 5, 6, 6, 7],
+    }
+    expected = {"a": [1, 2, 3, 4], "b": [4, 5, 6, 7]}
+    assert sorted_dict(df.union(df1, distinct=True)) == expected
+    assert sorted_dict(df.union_distinct(df1)) == expected
+    assert sorted_dict(df.union(df).distinct()) == {
+        "a": [1, 2, 3],
+        "b": [4, 5, 6],
+    }
+    assert sorted_dict(df.intersect(df1)) == {"a": [3], "b": [6]}
+    assert sorted_dict(df.except_(df1)) == {"a": [1, 2], "b": [4, 5]}
+
+
+def test_union_schema_mismatch(df):
+    with pytest.raises(Exception):
+        df.union(df.select(column("a")))
+
+
 @pytest.mark.skip(
     reason="https://github.com/apache/arrow-datafusion-python/issues/135"
 )
@@ -165,6 +509,87 @@ def test_window_lead(df):
     assert table.to_pydict() == expected
 
 
+def test_window_frame(df):
+    df = df.select(
+        column("a"),
+        f.alias(
+            f.window(
+                "sum",
+                [column("a")],
+                order_by=[f.order_by(column("a"))],
+                window_frame=WindowFrame("rows", 1, 0),
+            ),
+            "a_sum",
+        ),
+    )
+
+    table = pa.Table.from_batches(df.sort(column("a").sort()).collect())
+
+    expected = {"a": [1, 2, 3], "a_sum": [1, 3, 5]}
+    assert table.to_pydict() == expected
+
+
+@pytest.mark.parametrize(
+    "window_frame,expected",
+    [
+        (
+            WindowFrame("rows", ("preceding", 3), ("preceding", 1)),
+            [None, 1, 3],
+        ),
+        (
+            WindowFrame("rows", ("following", 1), ("following", 3)),
+            [5, 3, None],
+        ),
+        (
+            WindowFrame("rows", ("preceding", None), ("current_row", None)),
+            [1, 3, 6],
+        ),
+        (WindowFrame("range", None, 0), [1, 3, 6]),
+        (WindowFrame("range", 0, None), [6, 5, 3]),
+        (WindowFrame("groups", 1, 0), [1, 3, 5]),
+        (WindowFrame("groups", 0, 1), [3, 5, 3]),
+    ],
+)
+def test_window_frame_bounds(df, window_frame, expected):
+    df = df.select(
+        column("a"),
+        f.alias(
+            f.window(
+                "sum",
+                [column("a")],
+                order_by=[f.order_by(column("a"))],
+                window_frame=window_frame,
+            ),
+            "a_sum",
+        ),
+    )
+
+    table = pa.Table.from_batches(df.sort(column("a").sort()).collect())
+    assert table.to_pydict() == {"a": [1, 2, 3], "a_sum": expected}
+
+
+def test_window_frame_units():
+    assert "ROWS" in str(WindowFrame("rows", 2, 0))
+    assert "RANGE" in str(WindowFrame("range", None, None))
+    assert "GROUPS" in str(WindowFrame("groups", 1, 1))
+
+    with pytest.raises(Exception, match="Unsupported window frame units"):
+        WindowFrame("foo", None, None)
+
+
+def test_invalid_window_frame_bounds():
+    with pytest.raises(Exception, match="cannot come after"):
+        WindowFrame("rows", ("preceding", 1), ("preceding", 3))
+    with pytest.raises(Exception, match="cannot come after"):
+        WindowFrame("rows", ("following", 1), ("current_row", None))
+    with pytest.raises(Exception, match="unbounded following"):
+        WindowFrame("rows", ("following", None), None)
+    with pytest.raises(Exception, match="unbounded preceding"):
+        WindowFrame("rows", None, ("preceding", None))
+    with pytest.raises(Exception, match="Unsupported window frame bound"):
+        WindowFrame("rows", ("before", 1), 0)
+
+
 def test_get_dataframe(tmp_path):
     ctx = SessionContext()
 
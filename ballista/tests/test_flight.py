@@ -0,0 +1,59 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import pyarrow as pa
+import pytest
+
+from ballista.flight import FlightEndpoint, read_endpoints
+
+pa_flight = pytest.importorskip("pyarrow.flight")
+
+
+class PartitionServer(pa_flight.FlightServerBase):
+    """Serves one table per ticket, like the executors do for partitions"""
+
+    def __init__(self, partitions):
+        super().__init__("grpc://127.0.0.1:0")
+        self.partitions = partitions
+
+    def do_get(self, context, ticket):
+        return pa_flight.RecordBatchStream(self.partitions[ticket.ticket])
+
+
+def test_flight_endpoint():
+    endpoint = FlightEndpoint("executor-host", 50051, b"ticket")
+
+    assert endpoint.host == "executor-host"
+    assert endpoint.port == 50051
+    assert endpoint.ticket == b"ticket"
+    assert endpoint.uri == "grpc://executor-host:50051"
+    assert repr(endpoint) == "FlightEndpoint(grpc://executor-host:50051)"
+
+
+def test_read_endpoints():
+    partitions = {
+        b"p0": pa.table({"a": [1, 2]}),
+        b"p1": pa.table({"a": [3]}),
+    }
+    with PartitionServer(partitions) as server:
+        endpoints = [
+            FlightEndpoint("127.0.0.1", server.port, ticket)
+            for ticket in partitions
+        ]
+        table = read_endpoints(endpoints)
+
+    assert table.to_pydict() == {"a": [1, 2, 3]}
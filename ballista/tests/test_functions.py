@@ -15,6 +15,9 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import datetime
+from decimal import Decimal
+
 import numpy as np
 import pyarrow as pa
 import pytest
@@ -55,6 +58,60 @@ def test_literal(df):
     assert result.column(5) == pa.array([b"hello world"] * 3)
 
 
+def test_rich_literals(df):
+    df = df.select(
+        literal(datetime.datetime(2023, 1, 2, 3, 4, 5)),
+        literal(datetime.date(2023, 1, 2)),
+        literal(Decimal("1.23")),
+        literal([1, 2]),
+        literal(None),
+        literal(datetime.date(2023, 1, 2)) + datetime.timedelta(days=1),
+    )
+    result = df.collect()[0]
+    assert result.column(0) == pa.array(
+        [datetime.datetime(2023, 1, 2, 3, 4, 5)] * 3, pa.timestamp("us")
+    )
+    assert result.column(1) == pa.array([datetime.date(2023, 1, 2)] * 3)
+    assert result.column(2) == pa.array([Decimal("1.23")] * 3)
+    assert result.column(3) == pa.array([[1, 2]] * 3)
+    assert result.column(4).null_count == 3
+    assert result.column(5) == pa.array([datetime.date(2023, 1, 3)] * 3)
+
+
+def test_python_values_as_operands(df):
+    df = df.select(
+        column("b") + 1,
+        column("b") * 2.0,
+        column("b") > 4,
+        column("a") == "World",
+    )
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([5, 6, 7])
+    assert result.column(1) == pa.array([8.0, 10.0, 12.0])
+    assert result.column(2) == pa.array([False, True, True])
+    assert result.column(3) == pa.array([False, True, False])
+
+
+def test_python_values_as_left_operands(df):
+    df = df.select(
+        1 + column("b"),
+        10 - column("b"),
+        2.0 * column("b"),
+        60 / column("b"),
+        7 % column("b"),
+        True & (column("b") > 4),
+        False | (column("b") > 5),
+    )
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([5, 6, 7])
+    assert result.column(1) == pa.array([6, 5, 4])
+    assert result.column(2) == pa.array([8.0, 10.0, 12.0])
+    assert result.column(3) == pa.array([15, 12, 10])
+    assert result.column(4) == pa.array([3, 2, 1])
+    assert result.column(5) == pa.array([False, True, True])
+    assert result.column(6) == pa.array([False, False, True])
+
+
 def test_lit_arith(df):
     """
     Test literals with arithmetic operations
@@ -217,3 +274,119 @@ def test_hash_functions(df):
             ),
         ]
     )
+
+
+def test_case(df):
+    df = df.select(
+        f.case(column("b"))
+        .when(literal(4), literal("four"))
+        .when(literal(5), literal("five"))
+        .otherwise(literal("other")),
+        f.case(column("b")).when(literal(4), literal("four")).end(),
+        f.when(column("b") > literal(4), literal(True))
+        .otherwise(literal(False)),
+    )
+
+    result = df.collect()[0]
+    assert result.column(0) == pa.array(["four", "five", "other"])
+    assert result.column(1) == pa.array(["four", None, None])
+    assert result.column(2) == pa.array([False, True, True])
+
+
+def test_cast(df):
+    df = df.select(
+        column("b").cast(pa.float64()),
+        column("b").cast(pa.string()),
+        column("a").try_cast(pa.int64()),
+        literal("12").try_cast(pa.int32()),
+    )
+
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([4.0, 5.0, 6.0])
+    assert result.column(1) == pa.array(["4", "5", "6"])
+    assert result.column(2) == pa.array([None, None, None], pa.int64())
+    assert result.column(3) == pa.array([12, 12, 12], pa.int32())
+
+
+def test_nested_expressions():
+    ctx = SessionContext()
+    batch = pa.RecordBatch.from_arrays(
+        [
+            pa.array([{"x": 1, "y": "a"}, {"x": 2, "y": "b"}]),
+            pa.array([[1, 2], [3, 4]]),
+        ],
+        names=["s", "l"],
+    )
+    df = ctx.create_dataframe([[batch]])
+
+    df = df.select(
+        column("s")["x"],
+        column("s").get_field("y"),
+        column("l")[1],
+        f.named_struct("a", column("l")[0], "b", literal(1)),
+        f.struct(column("l")[0]),
+    )
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([1, 2])
+    assert result.column(1) == pa.array(["a", "b"])
+    assert result.column(2) == pa.array([2, 4])
+    assert result.column(3).to_pylist() == [
+        {"a": 1, "b": 1},
+        {"a": 3, "b": 1},
+    ]
+    assert result.column(4).to_pylist() == [{"c0": 1}, {"c0": 3}]
+
+
+def test_named_struct_of_literals(df):
+    df = df.select(f.named_struct("x", literal(1), "y", literal("a")))
+    result = df.collect()[0]
+    assert result.column(0).to_pylist() == [{"x": 1, "y": "a"}] * 3
+
+
+def test_named_struct_without_fields():
+    with pytest.raises(ValueError, match="at least one field"):
+        f.named_struct()
+
+
+def test_uuid(df):
+    result = df.select(f.uuid()).collect()[0]
+    values = result.column(0).to_pylist()
+    assert len(set(values)) == 3
+    assert all(len(value) == 36 for value in values)
+
+
+def test_predicates():
+    ctx = SessionContext()
+    batch = pa.RecordBatch.from_arrays(
+        [
+            pa.array(["Hello", "world", None]),
+            pa.array([1, 5, 10]),
+            pa.array([True, False, None]),
+        ],
+        names=["a", "b", "c"],
+    )
+    df = ctx.create_dataframe([[batch]])
+
+    df = df.select(
+        column("a").is_null(),
+        column("a").is_not_null(),
+        column("c").is_true(),
+        column("b").between(2, 10),
+        column("b").between(2, 10, negated=True),
+        column("a").like("H%"),
+        column("a").ilike("h%"),
+        column("a").similar_to("w.*"),
+        column("b").in_([1, 10]),
+        column("b").in_([literal(1)], negated=True),
+    )
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([False, False, True])
+    assert result.column(1) == pa.array([True, True, False])
+    assert result.column(2) == pa.array([True, False, False])
+    assert result.column(3) == pa.array([False, True, True])
+    assert result.column(4) == pa.array([True, False, False])
+    assert result.column(5) == pa.array([True, False, None])
+    assert result.column(6) == pa.array([True, False, None])
+    assert result.column(7) == pa.array([False, True, None])
+    assert result.column(8) == pa.array([True, False, True])
+    assert result.column(9) == pa.array([False, True, True])
@@ -54,6 +54,13 @@ def test_import_from_functions_submodule():
         from ballista.functions import foobar  # noqa
 
 
+def test_import_from_flight_submodule():
+    from ballista.flight import FlightEndpoint, read_endpoints  # noqa
+
+    assert FlightEndpoint.__module__ == "ballista.flight"
+    assert callable(read_endpoints)
+
+
 def test_classes_are_inheritable():
     class MyExecContext(SessionContext):
         pass
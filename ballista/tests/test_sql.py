@@ -17,9 +17,17 @@
 
 import numpy as np
 import pyarrow as pa
+import pyarrow.compute as pc
+import pyarrow.parquet as pq
 import pytest
 
-from ballista import SessionContext, udf
+from ballista import SessionContext, column, udf
+from ballista.object_store import (
+    AmazonS3,
+    GoogleCloud,
+    LocalFileSystem,
+    MicrosoftAzure,
+)
 
 from . import generic as helpers
 
@@ -82,6 +90,127 @@ def test_register_csv(ctx, tmp_path):
         ctx.register_csv("csv4", path, delimiter="wrong")
 
 
+def test_register_csv_partitioned(ctx, tmp_path):
+    for year in [2021, 2022]:
+        directory = tmp_path / f"year={year}"
+        directory.mkdir()
+        table = pa.table({"a": [1, 2]})
+        pa.csv.write_csv(table, directory / "data.csv")
+
+    ctx.register_csv(
+        "csv", tmp_path, table_partition_cols=[("year", pa.string())]
+    )
+    result = ctx.sql(
+        "SELECT year, SUM(a) AS s FROM csv GROUP BY year ORDER BY year"
+    ).collect()
+    result = pa.Table.from_batches(result)
+    assert result.to_pydict() == {"year": ["2021", "2022"], "s": [3, 3]}
+
+    df = ctx.read_csv(tmp_path, table_partition_cols=[("year", pa.string())])
+    assert df.count() == 4
+    assert df.schema().names == ["a", "year"]
+    assert df.filter(column("year") == "2022").count() == 2
+
+    with pytest.raises(
+        ValueError, match="Delimiter must be a single character"
+    ):
+        ctx.read_csv(tmp_path, delimiter="wrong")
+
+
+def test_register_json(ctx, tmp_path):
+    path = tmp_path / "data.json"
+    path.write_text('{"a": 1, "b": "x"}\n{"a": 2, "b": "y"}\n')
+
+    ctx.register_json("json", str(path))
+    result = ctx.sql("SELECT SUM(a) AS s FROM json").collect()
+    assert result[0].column(0) == pa.array([3])
+
+    schema = pa.schema([("a", pa.int32()), ("b", pa.string())])
+    df = ctx.read_json(str(path), schema=schema)
+    assert df.schema() == schema
+    assert df.to_pydict() == {"a": [1, 2], "b": ["x", "y"]}
+
+
+def test_read_parquet(ctx, tmp_path):
+    for part in ["x", "y"]:
+        directory = tmp_path / f"part={part}"
+        directory.mkdir()
+        helpers.write_parquet(directory / "a.parquet", pa.array([1, 2, 3]))
+
+    df = ctx.read_parquet(
+        str(tmp_path),
+        table_partition_cols=[("part", pa.string())],
+        parquet_pruning=False,
+    )
+    assert df.count() == 6
+
+    ctx.register_parquet(
+        "t", str(tmp_path), table_partition_cols=[("part", pa.string())]
+    )
+    result = ctx.sql("SELECT COUNT(a) AS cnt FROM t WHERE part = 'y'")
+    assert result.collect()[0].column(0) == pa.array([3])
+
+
+def test_read_parquet_skip_metadata(ctx, tmp_path):
+    table = pa.table({"a": [1, 2, 3]}).replace_schema_metadata(
+        {"origin": "test"}
+    )
+    path = str(tmp_path / "a.parquet")
+    pq.write_table(table, path)
+
+    df = ctx.read_parquet(path, skip_metadata=False)
+    assert df.schema().metadata[b"origin"] == b"test"
+
+    df = ctx.read_parquet(path)
+    assert not df.schema().metadata
+
+    ctx.register_parquet("t", path, skip_metadata=False)
+    assert ctx.table("t").schema().metadata[b"origin"] == b"test"
+
+
+def test_register_object_store(ctx, tmp_path):
+    helpers.write_parquet(tmp_path / "a.parquet", pa.array([1, 2, 3]))
+
+    # the scheme is unknown until a store is registered for it
+    with pytest.raises(Exception, match="store"):
+        ctx.register_parquet("t", "myfs://data/a.parquet")
+
+    store = LocalFileSystem(prefix=str(tmp_path))
+    ctx.register_object_store("myfs", "data", store)
+    ctx.register_parquet("t", "myfs://data/a.parquet")
+
+    result = ctx.sql("SELECT SUM(a) AS s FROM t").collect()
+    assert result[0].column(0) == pa.array([6])
+
+
+def test_register_s3_object_store(ctx):
+    s3 = AmazonS3(
+        "bucket",
+        region="us-east-1",
+        access_key_id="key",
+        secret_access_key="secret",
+        endpoint="http://localhost:9000",
+        allow_http=True,
+    )
+    ctx.register_object_store("s3", None, s3)
+    ctx.register_object_store("s3", "other-bucket", s3)
+
+
+def test_register_invalid_object_store(ctx, tmp_path):
+    with pytest.raises(TypeError):
+        ctx.register_object_store("s3", None, "not a store")
+
+    with pytest.raises(ValueError):
+        LocalFileSystem(prefix=str(tmp_path / "missing"))
+    with pytest.raises(ValueError):
+        GoogleCloud(
+            "bucket", service_account_path=str(tmp_path / "missing.json")
+        )
+    # an account is required unless the emulator is used
+    with pytest.raises(ValueError):
+        MicrosoftAzure("container", access_key="key")
+
+
 def test_register_parquet(ctx, tmp_path):
     path = helpers.write_parquet(tmp_path / "a.parquet", helpers.data())
     ctx.register_parquet("t", path)
@@ -92,6 +221,17 @@ def test_register_parquet(ctx, tmp_path):
     assert result.to_pydict() == {"cnt": [100]}
 
 
+def test_plan_to_proto(ctx, tmp_path):
+    path = helpers.write_parquet(
+        tmp_path / "a.parquet", pa.array([1, 2, 3])
+    )
+    ctx.register_parquet("t", path)
+
+    df = ctx.sql("SELECT a FROM t WHERE a > 1")
+    assert isinstance(df.logical_plan().to_proto(), bytes)
+    assert isinstance(df.execution_plan().to_proto(), bytes)
+
+
 def test_execute(ctx, tmp_path):
     data = [1, 1, 2, 2, 3, 11, 12]
 
@@ -209,6 +349,94 @@ def test_udf(
     assert result == pa.array(expected_values)
 
 
+def test_sql_with_params(ctx):
+    batch = pa.RecordBatch.from_arrays(
+        [pa.array([1, 2, 3]), pa.array(["a", "b", "c"])],
+        names=["x", "y"],
+    )
+    ctx.register_record_batches("t", [[batch]])
+
+    df = ctx.sql_with_params(
+        "SELECT x, y FROM t WHERE x > $1 AND y <> $2", [1, pa.scalar("c")]
+    )
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([2])
+    assert result.column(1) == pa.array(["b"])
+
+    # parameters are bound as values, never spliced into the query
+    df = ctx.sql_with_params("SELECT x FROM t WHERE y = $1", ["a' OR '1'='1"])
+    assert sum(batch.num_rows for batch in df.collect()) == 0
+
+    df = ctx.sql_with_params("SELECT x FROM t WHERE x = $1 OR x = $1", [3])
+    assert df.collect()[0].column(0) == pa.array([3])
+
+    with pytest.raises(ValueError, match="expects 2 parameters but 1"):
+        ctx.sql_with_params("SELECT x FROM t WHERE x > $1 AND x < $2", [1])
+    with pytest.raises(ValueError, match="expects 1 parameters but 2"):
+        ctx.sql_with_params("SELECT x FROM t WHERE x > $1", [1, 2])
+
+
+def test_sql_with_params_in_subquery(ctx):
+    batch = pa.RecordBatch.from_arrays(
+        [pa.array([1, 2, 3]), pa.array(["a", "b", "c"])],
+        names=["x", "y"],
+    )
+    ctx.register_record_batches("t", [[batch]])
+    query = (
+        "SELECT x FROM t WHERE x > $1 "
+        "AND x IN (SELECT x FROM t WHERE y <> $2)"
+    )
+
+    df = ctx.sql_with_params(query, [1, "c"])
+    assert df.collect()[0].column(0) == pa.array([2])
+
+    with pytest.raises(ValueError, match="expects 2 parameters but 1"):
+        ctx.sql_with_params(query, [1])
+
+
+def test_register_udf_by_name(ctx, tmp_path):
+    path = helpers.write_parquet(
+        tmp_path / "a.parquet", pa.array([1, 2, 3])
+    )
+    ctx.register_parquet("t", path)
+
+    double = ctx.register_udf(
+        "double",
+        lambda x: pc.multiply(x, 2.0),
+        [pa.int64()],
+        pa.float64(),
+    )
+    assert double.name == "double"
+
+    batches = ctx.sql("SELECT double(a) AS tt FROM t").collect()
+    assert batches[0].column(0) == pa.array([2.0, 4.0, 6.0])
+
+    batches = ctx.table("t").select(double(column("a"))).collect()
+    assert batches[0].column(0) == pa.array([2.0, 4.0, 6.0])
+
+
+def test_register_udf_by_name_with_kwargs(ctx):
+    ctx.register_record_batches(
+        "t", [[pa.RecordBatch.from_arrays([pa.array([1, 2, 3])], ["a"])]]
+    )
+
+    # volatility keeps its default
+    ctx.register_udf(
+        "double",
+        lambda x: pc.multiply(x, 2),
+        input_types=[pa.int64()],
+        return_type=pa.int64(),
+    )
+
+    batches = ctx.sql("SELECT double(a) FROM t").collect()
+    assert batches[0].column(0) == pa.array([2, 4, 6])
+
+
+def test_register_udf_by_name_requires_signature(ctx):
+    with pytest.raises(ValueError):
+        ctx.register_udf("double", lambda x: x)
+
+
 _null_mask = np.array([False, True, False])
 
 
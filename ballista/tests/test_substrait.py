@@ -0,0 +1,59 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import pyarrow as pa
+import pytest
+
+from ballista import SessionContext
+from ballista import substrait as ss
+
+
+@pytest.fixture
+def ctx():
+    ctx = SessionContext()
+    batch = pa.RecordBatch.from_arrays(
+        [pa.array([1, 2, 3]), pa.array([4, 5, 6])],
+        names=["a", "b"],
+    )
+    ctx.register_record_batches("t", [[batch]])
+    return ctx
+
+
+def test_substrait_roundtrip(ctx):
+    df = ctx.sql("SELECT a, b FROM t WHERE a > 1")
+
+    plan = df.to_substrait()
+    assert isinstance(plan, bytes)
+
+    result = ctx.from_substrait(plan).collect()[0]
+    assert result.column(0) == pa.array([2, 3])
+    assert result.column(1) == pa.array([5, 6])
+
+
+def test_substrait_submodule(ctx):
+    df = ctx.sql("SELECT a FROM t")
+
+    plan = ss.to_substrait(df)
+    assert plan == df.to_substrait()
+
+    result = ss.from_substrait(ctx, plan).collect()[0]
+    assert result.column(0) == pa.array([1, 2, 3])
+
+
+def test_invalid_substrait_plan(ctx):
+    with pytest.raises(Exception):
+        ctx.from_substrait(b"not a plan")
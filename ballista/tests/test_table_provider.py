@@ -0,0 +1,110 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+import pyarrow as pa
+import pytest
+
+from ballista import SessionContext, TableProvider
+
+
+class InMemoryProvider(TableProvider):
+    """
+    A provider recording the arguments it is scanned with.
+    """
+
+    def __init__(self):
+        self.table = pa.table({"a": [1, 2, 3], "b": [4, 5, 6]})
+        self.scans = []
+
+    def schema(self):
+        return self.table.schema
+
+    def scan(self, projection, filters, limit):
+        self.scans.append((projection, [str(f) for f in filters], limit))
+        table = self.table
+        if projection is not None:
+            table = table.select(projection)
+        return table.to_batches()
+
+    def supports_filter_pushdown(self, filter):
+        return True
+
+
+@pytest.fixture
+def ctx():
+    return SessionContext()
+
+
+def test_register_table_provider(ctx):
+    provider = InMemoryProvider()
+    ctx.register_table_provider("t", provider)
+
+    result = ctx.sql("SELECT b FROM t WHERE a > 1").collect()[0]
+    assert result.column(0) == pa.array([5, 6])
+
+    projection, filters, limit = provider.scans[-1]
+    assert "b" in projection
+    assert len(filters) == 1
+
+
+def test_table_provider_limit(ctx):
+    provider = InMemoryProvider()
+    ctx.register_table_provider("t", provider)
+
+    ctx.sql("SELECT a FROM t LIMIT 2").collect()
+    assert provider.scans[-1][2] == 2
+
+
+def test_table_provider_returning_table(ctx):
+    class TableReturningProvider(InMemoryProvider):
+        def scan(self, projection, filters, limit):
+            return self.table.select(projection) if projection else self.table
+
+    ctx.register_table_provider("t", TableReturningProvider())
+
+    result = ctx.sql("SELECT a, b FROM t").collect()[0]
+    assert result.column(0) == pa.array([1, 2, 3])
+    assert result.column(1) == pa.array([4, 5, 6])
+
+
+def test_table_provider_ignoring_projection(ctx):
+    class FullWidthProvider(InMemoryProvider):
+        def scan(self, projection, filters, limit):
+            # returns the columns in another order, and all of them
+            return self.table.select(["b", "a"])
+
+    ctx.register_table_provider("t", FullWidthProvider())
+
+    result = ctx.sql("SELECT a FROM t").collect()[0]
+    assert result.num_columns == 1
+    assert result.column(0) == pa.array([1, 2, 3])
+
+
+def test_table_provider_missing_column(ctx):
+    class MissingColumnProvider(InMemoryProvider):
+        def scan(self, projection, filters, limit):
+            return self.table.select(["b"])
+
+    ctx.register_table_provider("t", MissingColumnProvider())
+
+    with pytest.raises(Exception, match="Unable to get field named"):
+        ctx.sql("SELECT a FROM t").collect()
+
+
+def test_invalid_table_provider(ctx):
+    with pytest.raises(ValueError):
+        ctx.register_table_provider("t", object())
@@ -22,6 +22,7 @@ import pyarrow.compute as pc
 import pytest
 
 from ballista import Accumulator, SessionContext, column, udaf
+from ballista import functions as f
 
 
 class Summarize(Accumulator):
@@ -134,3 +135,36 @@ def test_group_by(df):
     arrays = [batch.column(1) for batch in batches]
     joined = pa.concat_arrays(arrays)
     assert joined == pa.array([1.0 + 2.0, 3.0])
+
+
+def test_register_udaf_by_name():
+    ctx = SessionContext()
+    batch = pa.RecordBatch.from_arrays(
+        [pa.array([1.0, 2.0, 3.0])], names=["a"]
+    )
+    ctx.register_record_batches("t", [[batch]])
+
+    summarize = ctx.register_udaf(
+        "summarize",
+        Summarize,
+        pa.float64(),
+        pa.float64(),
+        [pa.float64()],
+    )
+    assert summarize.name == "summarize"
+
+    result = ctx.sql("SELECT summarize(a) FROM t").collect()[0]
+    assert result.column(0) == pa.array([1.0 + 2.0 + 3.0])
+
+    df = ctx.table("t").aggregate([], [summarize(column("a"))])
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([1.0 + 2.0 + 3.0])
+
+
+def test_functions_udaf(df):
+    summarize = f.udaf(Summarize, pa.float64(), pa.float64(), [pa.float64()])
+
+    df = df.aggregate([], [summarize(column("a"))])
+
+    result = df.collect()[0]
+    assert result.column(0) == pa.array([1.0 + 2.0 + 3.0])
@@ -0,0 +1,110 @@
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+
+
+"""
+Measures the cost of building long DataFrame method chains from Python,
+without executing them.
+
+    python benchmarks/dataframe_chain.py [--columns N] [--steps N]
+
+Chained `with_column` calls are projected together once the DataFrame is
+used. They are compared against the previous behaviour, one full-width
+projection per call, which `eager_with_column` reproduces with `select`.
+Every timing includes building the final logical plan.
+
+Other methods add a single node per call, whose inputs are shared with the
+previous DataFrame, so their cost per call should stay flat as the chain
+gets deeper.
+"""
+
+import argparse
+import time
+
+import pyarrow as pa
+
+from ballista import SessionContext, column, literal
+
+
+def base_dataframe(columns):
+    ctx = SessionContext()
+    batch = pa.RecordBatch.from_arrays(
+        [pa.array([1, 2, 3]) for _ in range(columns)],
+        names=[f"c{i}" for i in range(columns)],
+    )
+    return ctx.create_dataframe([[batch]])
+
+
+def timed(label, fn):
+    start = time.perf_counter()
+    fn().logical_plan()
+    elapsed = time.perf_counter() - start
+    print(f"{label:<48} {elapsed * 1000:10.2f} ms")
+
+
+def chained_filters(df, steps):
+    for i in range(steps):
+        df = df.filter(column("c0") > literal(i))
+    return df
+
+
+def eager_with_column(df, name, expr):
+    columns = [column(c) for c in df.schema().names if c != name]
+    return df.select(*columns, expr.alias(name))
+
+
+def chained_with_column(df, steps, eager=False):
+    for i in range(steps):
+        name, expr = f"new{i}", column("c0") + literal(i)
+        if eager:
+            df = eager_with_column(df, name, expr)
+        else:
+            df = df.with_column(name, expr)
+    return df
+
+
+def main():
+    parser = argparse.ArgumentParser(
+        description=__doc__, formatter_class=argparse.RawTextHelpFormatter
+    )
+    parser.add_argument("--columns", type=int, default=100)
+    parser.add_argument("--steps", type=int, default=500)
+    args = parser.parse_args()
+
+    df = base_dataframe(args.columns)
+
+    timed(
+        f"{args.steps} with_column calls, one projection each",
+        lambda: chained_with_column(df, args.steps, eager=True),
+    )
+    timed(
+        f"{args.steps} with_column calls",
+        lambda: chained_with_column(df, args.steps),
+    )
+
+    # the cost per call should not depend on how deep the plan already is
+    half = args.steps // 2
+    deep = chained_filters(df, half)
+    timed(f"{half} filters on a fresh plan", lambda: chained_filters(df, half))
+    timed(
+        f"{half} filters on a plan {half} nodes deep",
+        lambda: chained_filters(deep, half),
+    )
+
+
+if __name__ == "__main__":
+    main()
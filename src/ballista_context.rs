@@ -15,18 +15,24 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
 use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::utils::wait_for_future;
+use crate::utils::{
+    avro_read_options, csv_read_options, parquet_read_options, wait_for_future,
+};
 
+use crate::config::PyBallistaConfig;
 use crate::dataframe::PyDataFrame;
+use crate::datatype::PyDataType;
 use crate::errors::BallistaError;
+use crate::job::PyJobHandle;
 use ballista::prelude::{BallistaConfig, BallistaContext};
 use datafusion::arrow::datatypes::Schema;
 use datafusion::arrow::pyarrow::PyArrowType;
-use datafusion::prelude::{AvroReadOptions, CsvReadOptions, ParquetReadOptions};
 
 /// `PyBallistaContext` is able to plan and execute DataFusion plans.
 /// It has a powerful optimizer, a physical planner for local execution, and a
@@ -34,33 +40,61 @@ use datafusion::prelude::{AvroReadOptions, CsvReadOptions, ParquetReadOptions};
 #[pyclass(name = "BallistaContext", module = "ballista", subclass, unsendable)]
 pub(crate) struct PyBallistaContext {
     ctx: BallistaContext,
+    config: BallistaConfig,
+    host: String,
+    port: u16,
 }
 
 #[pymethods]
 impl PyBallistaContext {
     #[new]
-    #[args(port = "50050", shuffle_partitions = 16, batch_size = 8192)]
+    #[args(
+        port = "50050",
+        shuffle_partitions = 16,
+        batch_size = 8192,
+        config = "None"
+    )]
     fn new(
         py: Python,
         host: &str,
         port: u16,
         shuffle_partitions: usize,
         batch_size: usize,
+        config: Option<PyBallistaConfig>,
     ) -> PyResult<Self> {
-        let config = BallistaConfig::builder()
-            .set(
-                "ballista.shuffle.partitions",
-                &format!("{}", shuffle_partitions),
-            )
-            .set("ballista.batch.size", &format!("{}", batch_size))
-            .set("ballista.with_information_schema", "true")
-            .build()
-            .map_err(BallistaError::from)?;
-
-        let result = BallistaContext::remote(host, port, &config);
-        let ctx = wait_for_future(py, result).map_err(BallistaError::from)?;
+        // an explicit config takes precedence over the keyword arguments
+        let config = config.unwrap_or_else(|| {
+            PyBallistaConfig::new()
+                .with_shuffle_partitions(shuffle_partitions)
+                .with_batch_size(batch_size)
+        });
+        Self::connect(py, host, port, &config, None)
+    }
 
-        Ok(PyBallistaContext { ctx })
+    /// Connects to a remote Ballista scheduler at `url`, of the form
+    /// `df://host:port`. `connect_timeout` is expressed in seconds.
+    /// TLS is not supported: the Ballista client only opens plaintext gRPC
+    /// connections, so `dfs://` and `grpcs://` urls are rejected.
+    #[staticmethod]
+    #[args(config = "None", connect_timeout = "None")]
+    fn remote(
+        py: Python,
+        url: &str,
+        config: Option<PyBallistaConfig>,
+        connect_timeout: Option<f64>,
+    ) -> PyResult<Self> {
+        let (host, port) = parse_scheduler_url(url)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        let config = config.unwrap_or_else(PyBallistaConfig::new);
+        let connect_timeout = match connect_timeout {
+            Some(secs) if !secs.is_finite() || secs <= 0.0 => {
+                return Err(PyValueError::new_err(
+                    "connect_timeout must be a positive number of seconds",
+                ))
+            }
+            secs => secs.map(Duration::from_secs_f64),
+        };
+        Self::connect(py, &host, port, &config, connect_timeout)
     }
 
     /// Returns a PyDataFrame whose plan corresponds to the SQL statement.
@@ -72,13 +106,16 @@ impl PyBallistaContext {
         Ok(PyDataFrame::new(df))
     }
 
+    /// Registers the CSV files at `path` as a table, with the same options
+    /// and limitations as `SessionContext.register_csv`.
     #[allow(clippy::too_many_arguments)]
     #[args(
         schema = "None",
         has_header = "true",
         delimiter = "\",\"",
         schema_infer_max_records = "1000",
-        file_extension = "\".csv\""
+        file_extension = "\".csv\"",
+        table_partition_cols = "vec![]"
     )]
     fn register_csv(
         &mut self,
@@ -89,6 +126,7 @@ impl PyBallistaContext {
         delimiter: &str,
         schema_infer_max_records: usize,
         file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
         py: Python,
     ) -> PyResult<()> {
         let ctx = &self.ctx;
@@ -96,20 +134,14 @@ impl PyBallistaContext {
         let path = path
             .to_str()
             .ok_or_else(|| PyValueError::new_err("Unable to convert path to a string"))?;
-
-        let delimiter = delimiter.as_bytes();
-        if delimiter.len() != 1 {
-            return Err(PyValueError::new_err(
-                "Delimiter must be a single character",
-            ));
-        }
-
-        let mut options = CsvReadOptions::new()
-            .has_header(has_header)
-            .delimiter(delimiter[0])
-            .schema_infer_max_records(schema_infer_max_records)
-            .file_extension(file_extension);
-        options.schema = schema.as_ref().map(|x| &x.0);
+        let options = csv_read_options(
+            schema.as_ref().map(|x| &x.0),
+            has_header,
+            delimiter,
+            schema_infer_max_records,
+            file_extension,
+            table_partition_cols,
+        )?;
 
         let result = ctx.register_csv(name, path, options);
         wait_for_future(py, result).map_err(BallistaError::from)?;
@@ -117,21 +149,284 @@ impl PyBallistaContext {
         Ok(())
     }
 
-    fn register_avro(&mut self, name: &str, path: &str, py: Python) -> PyResult<()> {
+    /// Submits the plan of `df` to the scheduler and returns a `JobHandle`
+    /// right away, instead of blocking until the job completes.
+    fn submit(&self, df: &PyDataFrame, py: Python) -> PyResult<PyJobHandle> {
+        let scheduler_url = format!("http://{}:{}", self.host, self.port);
+        PyJobHandle::submit(py, &scheduler_url, &self.config, df.dataframe()?)
+    }
+
+    /// Returns the Arrow Flight SQL endpoint of the scheduler, for external
+    /// tools to query the cluster. The scheduler must be built with its
+    /// `flight-sql` feature, it then serves Flight SQL on its gRPC port,
+    /// which is the one this context is connected to.
+    fn flight_endpoint(&self) -> String {
+        format!("grpc://{}:{}", self.host, self.port)
+    }
+
+    #[args(
+        schema = "None",
+        file_extension = "\".avro\"",
+        table_partition_cols = "vec![]"
+    )]
+    fn register_avro(
+        &mut self,
+        name: &str,
+        path: &str,
+        schema: Option<PyArrowType<Schema>>,
+        file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        py: Python,
+    ) -> PyResult<()> {
         let ctx = &self.ctx;
 
-        let result = ctx.register_avro(name, path, AvroReadOptions::default());
+        let options = avro_read_options(schema, file_extension, table_partition_cols);
+        let result = ctx.register_avro(name, path, options);
         wait_for_future(py, result).map_err(BallistaError::from)?;
 
         Ok(())
     }
 
-    fn register_parquet(&mut self, name: &str, path: &str, py: Python) -> PyResult<()> {
+    /// Registers the parquet files at `path` as a table, with the same
+    /// options as `SessionContext.register_parquet`.
+    #[allow(clippy::too_many_arguments)]
+    #[args(
+        table_partition_cols = "vec![]",
+        parquet_pruning = "true",
+        file_extension = "\".parquet\"",
+        skip_metadata = "true"
+    )]
+    fn register_parquet(
+        &mut self,
+        name: &str,
+        path: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        parquet_pruning: bool,
+        file_extension: &str,
+        skip_metadata: bool,
+        py: Python,
+    ) -> PyResult<()> {
         let ctx = &self.ctx;
 
-        let result = ctx.register_parquet(name, path, ParquetReadOptions::default());
+        let options = parquet_read_options(
+            table_partition_cols,
+            parquet_pruning,
+            file_extension,
+            skip_metadata,
+        );
+        let result = ctx.register_parquet(name, path, options);
         wait_for_future(py, result).map_err(BallistaError::from)?;
 
         Ok(())
     }
+
+    /// Newline-delimited JSON can't be read through the Ballista client yet,
+    /// use a `SessionContext` instead.
+    #[args(_args = "*", _kwargs = "**")]
+    fn register_json(&self, _args: &PyTuple, _kwargs: Option<&PyDict>) -> PyResult<()> {
+        Err(PyNotImplementedError::new_err(
+            "BallistaContext doesn't support JSON files, \
+             use SessionContext.register_json instead",
+        ))
+    }
+
+    /// Newline-delimited JSON can't be read through the Ballista client yet,
+    /// use a `SessionContext` instead.
+    #[args(_args = "*", _kwargs = "**")]
+    fn read_json(
+        &self,
+        _args: &PyTuple,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<PyDataFrame> {
+        Err(PyNotImplementedError::new_err(
+            "BallistaContext doesn't support JSON files, \
+             use SessionContext.read_json instead",
+        ))
+    }
+
+    /// Creates a DataFrame reading the CSV files at `path`, taking the same
+    /// options as `register_csv`.
+    #[allow(clippy::too_many_arguments)]
+    #[args(
+        schema = "None",
+        has_header = "true",
+        delimiter = "\",\"",
+        schema_infer_max_records = "1000",
+        file_extension = "\".csv\"",
+        table_partition_cols = "vec![]"
+    )]
+    fn read_csv(
+        &self,
+        path: PathBuf,
+        schema: Option<PyArrowType<Schema>>,
+        has_header: bool,
+        delimiter: &str,
+        schema_infer_max_records: usize,
+        file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let path = path
+            .to_str()
+            .ok_or_else(|| PyValueError::new_err("Unable to convert path to a string"))?;
+        let options = csv_read_options(
+            schema.as_ref().map(|x| &x.0),
+            has_header,
+            delimiter,
+            schema_infer_max_records,
+            file_extension,
+            table_partition_cols,
+        )?;
+
+        let result = self.ctx.read_csv(path, options);
+        let df = wait_for_future(py, result).map_err(BallistaError::from)?;
+        Ok(PyDataFrame::new(df))
+    }
+
+    /// Creates a DataFrame reading the Avro files at `path`, taking the same
+    /// options as `register_avro`.
+    #[args(
+        schema = "None",
+        file_extension = "\".avro\"",
+        table_partition_cols = "vec![]"
+    )]
+    fn read_avro(
+        &self,
+        path: &str,
+        schema: Option<PyArrowType<Schema>>,
+        file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let options = avro_read_options(schema, file_extension, table_partition_cols);
+        let result = self.ctx.read_avro(path, options);
+        let df = wait_for_future(py, result).map_err(BallistaError::from)?;
+        Ok(PyDataFrame::new(df))
+    }
+
+    /// Creates a DataFrame reading the parquet files at `path`, taking the
+    /// same options as `register_parquet`.
+    #[args(
+        table_partition_cols = "vec![]",
+        parquet_pruning = "true",
+        file_extension = "\".parquet\"",
+        skip_metadata = "true"
+    )]
+    fn read_parquet(
+        &self,
+        path: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        parquet_pruning: bool,
+        file_extension: &str,
+        skip_metadata: bool,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let options = parquet_read_options(
+            table_partition_cols,
+            parquet_pruning,
+            file_extension,
+            skip_metadata,
+        );
+        let result = self.ctx.read_parquet(path, options);
+        let df = wait_for_future(py, result).map_err(BallistaError::from)?;
+        Ok(PyDataFrame::new(df))
+    }
+}
+
+impl PyBallistaContext {
+    fn connect(
+        py: Python,
+        host: &str,
+        port: u16,
+        config: &PyBallistaConfig,
+        connect_timeout: Option<Duration>,
+    ) -> PyResult<Self> {
+        let config = config.build()?;
+
+        let result = BallistaContext::remote(host, port, &config);
+        let ctx = match connect_timeout {
+            Some(connect_timeout) => {
+                // the timer needs the runtime `wait_for_future` creates
+                let result =
+                    async move { tokio::time::timeout(connect_timeout, result).await };
+                wait_for_future(py, result).map_err(|_| {
+                    BallistaError::Common(format!(
+                        "Timed out connecting to the scheduler at {}:{}",
+                        host, port
+                    ))
+                })?
+            }
+            None => wait_for_future(py, result),
+        }
+        .map_err(BallistaError::from)?;
+
+        Ok(PyBallistaContext {
+            ctx,
+            config,
+            host: host.to_string(),
+            port,
+        })
+    }
+}
+
+/// Splits a scheduler url of the form `df://host:port` into its host and port.
+/// The scheme is optional and the port defaults to 50050.
+fn parse_scheduler_url(url: &str) -> Result<(String, u16), BallistaError> {
+    let address = match url.split_once("://") {
+        Some(("df", address)) => address,
+        // the Ballista client only opens plaintext connections
+        Some((scheme @ ("dfs" | "grpcs" | "https"), _)) => {
+            return Err(BallistaError::Common(format!(
+                "TLS connections to the scheduler (`{}://`) are not supported, \
+                 expected `df://host:port`",
+                scheme
+            )))
+        }
+        Some((scheme, _)) => {
+            return Err(BallistaError::Common(format!(
+                "Unsupported scheduler url scheme `{}`, expected `df://host:port`",
+                scheme
+            )))
+        }
+        None => url,
+    };
+    let address = address.trim_end_matches('/');
+
+    match address.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port.parse::<u16>().map_err(|_| {
+                BallistaError::Common(format!("Invalid scheduler port `{}`", port))
+            })?;
+            Ok((host.to_owned(), port))
+        }
+        None => Ok((address.to_owned(), 50050)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_scheduler_url;
+
+    #[test]
+    fn parse_valid_scheduler_urls() {
+        for url in [
+            "df://scheduler:50051",
+            "df://scheduler:50051/",
+            "scheduler:50051",
+        ] {
+            let (host, port) = parse_scheduler_url(url).unwrap();
+            assert_eq!((host.as_str(), port), ("scheduler", 50051));
+        }
+
+        let (host, port) = parse_scheduler_url("df://scheduler").unwrap();
+        assert_eq!((host.as_str(), port), ("scheduler", 50050));
+    }
+
+    #[test]
+    fn parse_invalid_scheduler_urls() {
+        assert!(parse_scheduler_url("http://scheduler:50050").is_err());
+        assert!(parse_scheduler_url("dfs://scheduler:50050").is_err());
+        assert!(parse_scheduler_url("df://scheduler:port").is_err());
+        assert!(parse_scheduler_url("df://scheduler:70000").is_err());
+    }
 }
@@ -99,6 +99,10 @@ impl PyDatabase {
         }
     }
 
+    fn table_exists(&self, name: &str) -> bool {
+        self.database.table_exist(name)
+    }
+
     // register_table
     // deregister_table
 }
@@ -0,0 +1,51 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use datafusion_expr::conditional_expressions::CaseBuilder;
+use pyo3::prelude::*;
+
+use crate::expression::PyExpr;
+
+/// Builds a CASE expression one WHEN branch at a time
+#[pyclass(name = "CaseBuilder", module = "ballista", subclass)]
+pub(crate) struct PyCaseBuilder {
+    case_builder: CaseBuilder,
+}
+
+impl From<CaseBuilder> for PyCaseBuilder {
+    fn from(case_builder: CaseBuilder) -> PyCaseBuilder {
+        PyCaseBuilder { case_builder }
+    }
+}
+
+#[pymethods]
+impl PyCaseBuilder {
+    /// Adds a branch returning `then` when `when` matches
+    fn when(&mut self, when: PyExpr, then: PyExpr) -> PyCaseBuilder {
+        self.case_builder.when(when.expr, then.expr).into()
+    }
+
+    /// Finishes the expression, returning `else_expr` when no branch matches
+    fn otherwise(&mut self, else_expr: PyExpr) -> PyResult<PyExpr> {
+        Ok(self.case_builder.otherwise(else_expr.expr)?.into())
+    }
+
+    /// Finishes the expression, returning null when no branch matches
+    fn end(&mut self) -> PyResult<PyExpr> {
+        Ok(self.case_builder.end()?.into())
+    }
+}
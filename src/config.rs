@@ -0,0 +1,197 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeMap;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use ballista::prelude::BallistaConfig;
+use datafusion::execution::context::SessionConfig;
+
+use crate::errors::BallistaError;
+
+/// Configuration options of a `SessionContext`.
+/// Every setter returns a new config, so that calls can be chained.
+#[pyclass(name = "SessionConfig", module = "ballista", subclass)]
+#[derive(Clone, Default)]
+pub(crate) struct PySessionConfig {
+    pub(crate) config: SessionConfig,
+}
+
+impl From<SessionConfig> for PySessionConfig {
+    fn from(config: SessionConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[pymethods]
+impl PySessionConfig {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_create_default_catalog_and_schema(&self, enabled: bool) -> Self {
+        self.config
+            .clone()
+            .with_create_default_catalog_and_schema(enabled)
+            .into()
+    }
+
+    fn with_default_catalog_and_schema(&self, catalog: &str, schema: &str) -> Self {
+        self.config
+            .clone()
+            .with_default_catalog_and_schema(catalog, schema)
+            .into()
+    }
+
+    fn with_information_schema(&self, enabled: bool) -> Self {
+        self.config.clone().with_information_schema(enabled).into()
+    }
+
+    fn with_batch_size(&self, batch_size: usize) -> Self {
+        self.config.clone().with_batch_size(batch_size).into()
+    }
+
+    fn with_target_partitions(&self, target_partitions: usize) -> Self {
+        self.config
+            .clone()
+            .with_target_partitions(target_partitions)
+            .into()
+    }
+
+    fn with_repartition_joins(&self, enabled: bool) -> Self {
+        self.config.clone().with_repartition_joins(enabled).into()
+    }
+
+    fn with_repartition_aggregations(&self, enabled: bool) -> Self {
+        self.config
+            .clone()
+            .with_repartition_aggregations(enabled)
+            .into()
+    }
+
+    fn with_repartition_windows(&self, enabled: bool) -> Self {
+        self.config.clone().with_repartition_windows(enabled).into()
+    }
+
+    fn with_parquet_pruning(&self, enabled: bool) -> Self {
+        self.config.clone().with_parquet_pruning(enabled).into()
+    }
+
+    /// Sets any DataFusion configuration option, e.g.
+    /// `datafusion.optimizer.skip_failed_rules`
+    fn set(&self, key: &str, value: &str) -> PyResult<Self> {
+        let mut config = self.config.clone();
+        config
+            .options_mut()
+            .set(key, value)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+        Ok(config.into())
+    }
+
+    #[getter]
+    fn target_partitions(&self) -> usize {
+        self.config.target_partitions()
+    }
+
+    #[getter]
+    fn batch_size(&self) -> usize {
+        self.config.batch_size()
+    }
+}
+
+/// Configuration options of a `BallistaContext`.
+/// Every setter returns a new config, so that calls can be chained.
+#[pyclass(name = "BallistaConfig", module = "ballista", subclass)]
+#[derive(Clone, Debug)]
+pub(crate) struct PyBallistaConfig {
+    settings: BTreeMap<String, String>,
+}
+
+impl PyBallistaConfig {
+    /// Validates the settings and builds the Ballista config
+    pub fn build(&self) -> Result<BallistaConfig, BallistaError> {
+        let builder = self
+            .settings
+            .iter()
+            .fold(BallistaConfig::builder(), |builder, (key, value)| {
+                builder.set(key, value)
+            });
+        builder.build().map_err(BallistaError::from)
+    }
+
+    fn with(&self, key: &str, value: String) -> Self {
+        let mut settings = self.settings.clone();
+        settings.insert(key.to_owned(), value);
+        Self { settings }
+    }
+}
+
+#[pymethods]
+impl PyBallistaConfig {
+    #[new]
+    pub(crate) fn new() -> Self {
+        let settings = BTreeMap::from([(
+            "ballista.with_information_schema".to_owned(),
+            "true".to_owned(),
+        )]);
+        Self { settings }
+    }
+
+    pub(crate) fn with_shuffle_partitions(&self, shuffle_partitions: usize) -> Self {
+        self.with(
+            "ballista.shuffle.partitions",
+            shuffle_partitions.to_string(),
+        )
+    }
+
+    pub(crate) fn with_batch_size(&self, batch_size: usize) -> Self {
+        self.with("ballista.batch.size", batch_size.to_string())
+    }
+
+    fn with_repartition_joins(&self, enabled: bool) -> Self {
+        self.with("ballista.repartition.joins", enabled.to_string())
+    }
+
+    fn with_repartition_aggregations(&self, enabled: bool) -> Self {
+        self.with("ballista.repartition.aggregations", enabled.to_string())
+    }
+
+    fn with_repartition_windows(&self, enabled: bool) -> Self {
+        self.with("ballista.repartition.windows", enabled.to_string())
+    }
+
+    fn with_parquet_pruning(&self, enabled: bool) -> Self {
+        self.with("ballista.parquet.pruning", enabled.to_string())
+    }
+
+    fn with_information_schema(&self, enabled: bool) -> Self {
+        self.with("ballista.with_information_schema", enabled.to_string())
+    }
+
+    /// Sets any Ballista configuration option, e.g. `ballista.job.name`
+    fn set(&self, key: &str, value: &str) -> Self {
+        self.with(key, value.to_owned())
+    }
+
+    /// Returns the configured settings
+    fn settings(&self) -> BTreeMap<String, String> {
+        self.settings.clone()
+    }
+}
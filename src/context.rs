@@ -22,26 +22,47 @@ use uuid::Uuid;
 
 use pyo3::exceptions::{PyKeyError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 
-use datafusion::arrow::datatypes::{DataType, Schema};
+use datafusion::arrow::datatypes::{DataType, Schema, SchemaRef};
 use datafusion::arrow::pyarrow::PyArrowType;
 use datafusion::arrow::record_batch::RecordBatch;
 use datafusion::datasource::datasource::TableProvider;
 use datafusion::datasource::MemTable;
+use datafusion::error::DataFusionError as InnerDataFusionError;
 use datafusion::execution::context::{SessionConfig, SessionContext};
-use datafusion::prelude::{CsvReadOptions, DataFrame, ParquetReadOptions};
+use datafusion::logical_expr::expr_rewriter::{ExprRewritable, ExprRewriter};
+use datafusion::logical_expr::expr_visitor::{
+    ExprVisitable, ExpressionVisitor, Recursion,
+};
+use datafusion::logical_expr::utils::from_plan;
+use datafusion::logical_expr::{Expr, LogicalPlan, Subquery};
+use datafusion::prelude::DataFrame;
+use datafusion::scalar::ScalarValue;
+use datafusion_common::TableReference;
 
 use crate::catalog::{PyCatalog, PyTable};
+use crate::config::PySessionConfig;
 use crate::dataframe::PyDataFrame;
 use crate::dataset::Dataset;
 use crate::datatype::PyDataType;
 use crate::errors::DataFusionError;
+use crate::functions::named_struct_udf;
+use crate::store::StorageContexts;
+use crate::substrait::deserialize_dataframe;
+use crate::table_provider::PyTableProviderAdapter;
+use crate::udaf::PyAggregateUDF;
 use crate::udf::PyScalarUDF;
-use crate::utils::wait_for_future;
+use crate::utils::{
+    avro_read_options, csv_read_options, json_read_options, parquet_read_options,
+    py_obj_to_scalar_value, wait_for_future,
+};
 
 /// `PySessionContext` is able to plan and execute DataFusion plans.
 /// It has a powerful optimizer, a physical planner for local execution, and a
 /// multi-threaded execution engine to perform the execution.
+/// When a `config` is given, the other keyword arguments are ignored and
+/// every option is taken from the config.
 #[pyclass(name = "SessionContext", module = "ballista", subclass, unsendable)]
 pub(crate) struct PySessionContext {
     ctx: SessionContext,
@@ -59,7 +80,8 @@ impl PySessionContext {
         repartition_aggregations = "true",
         repartition_windows = "true",
         parquet_pruning = "true",
-        target_partitions = "None"
+        target_partitions = "None",
+        config = "None"
     )]
     #[new]
     fn new(
@@ -72,8 +94,13 @@ impl PySessionContext {
         repartition_windows: bool,
         parquet_pruning: bool,
         target_partitions: Option<usize>,
-        // TODO: config_options
+        config: Option<PySessionConfig>,
     ) -> Self {
+        // an explicit config takes precedence over the keyword arguments
+        if let Some(config) = config {
+            return PySessionContext::with_config(config.config);
+        }
+
         let cfg = SessionConfig::new()
             .with_create_default_catalog_and_schema(create_default_catalog_and_schema)
             .with_default_catalog_and_schema(default_catalog, default_schema)
@@ -88,9 +115,7 @@ impl PySessionContext {
             Some(x) => cfg.with_target_partitions(x),
         };
 
-        PySessionContext {
-            ctx: SessionContext::with_config(cfg_full),
-        }
+        PySessionContext::with_config(cfg_full)
     }
 
     /// Returns a PyDataFrame whose plan corresponds to the SQL statement.
@@ -100,27 +125,122 @@ impl PySessionContext {
         Ok(PyDataFrame::new(df))
     }
 
+    /// Creates a DataFrame from Substrait protobuf bytes, e.g. produced by
+    /// another engine.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_substrait(&self, plan: &PyBytes, py: Python) -> PyResult<PyDataFrame> {
+        let df = deserialize_dataframe(py, &self.ctx, plan.as_bytes())?;
+        Ok(PyDataFrame::new(df))
+    }
+
+    /// Returns a PyDataFrame whose plan corresponds to the SQL statement,
+    /// with the placeholders `$1`, `$2`, ... replaced by `params`.
+    fn sql_with_params(
+        &mut self,
+        query: &str,
+        params: Vec<&PyAny>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let params = params
+            .into_iter()
+            .map(|param| py_obj_to_scalar_value(py, param))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let result = self.ctx.sql(query);
+        let df = wait_for_future(py, result).map_err(DataFusionError::from)?;
+        let expected =
+            max_placeholder_index(df.logical_plan()).map_err(DataFusionError::from)?;
+        if expected != params.len() {
+            return Err(PyValueError::new_err(format!(
+                "The query expects {} parameters but {} were given",
+                expected,
+                params.len()
+            )));
+        }
+        let plan = bind_subquery_params(df.logical_plan(), &params)
+            .and_then(|plan| plan.replace_params_with_values(&params))
+            .map_err(DataFusionError::from)?;
+        Ok(PyDataFrame::new(DataFrame::new(self.ctx.state(), plan)))
+    }
+
     fn create_dataframe(
         &mut self,
         partitions: PyArrowType<Vec<Vec<RecordBatch>>>,
         py: Python,
     ) -> PyResult<PyDataFrame> {
-        let table = MemTable::try_new(partitions.0[0][0].schema(), partitions.0)
-            .map_err(DataFusionError::from)?;
+        let schema = partitions.0[0][0].schema();
+        self._create_dataframe(schema, partitions.0, None, py)
+    }
+
+    /// Creates a DataFrame from a `pyarrow.Table` or a
+    /// `pyarrow.RecordBatchReader`, registering it as `name` if given.
+    #[allow(clippy::wrong_self_convention)]
+    #[args(name = "None")]
+    fn from_arrow_table(
+        &mut self,
+        data: &PyAny,
+        name: Option<&str>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let data = if data.hasattr("read_all")? {
+            data.call_method0("read_all")?
+        } else {
+            data
+        };
+        let schema = data.getattr("schema")?.extract::<PyArrowType<Schema>>()?.0;
+        let batches = data
+            .call_method0("to_batches")?
+            .extract::<PyArrowType<Vec<RecordBatch>>>()?
+            .0;
+        self._create_dataframe(Arc::new(schema), vec![batches], name, py)
+    }
 
-        // generate a random (unique) name for this table
-        // table name cannot start with numeric digit
-        let name = "c".to_owned()
-            + Uuid::new_v4()
-                .to_simple()
-                .encode_lower(&mut Uuid::encode_buffer());
+    /// Creates a DataFrame from a `pandas.DataFrame`, registering it as
+    /// `name` if given.
+    #[allow(clippy::wrong_self_convention)]
+    #[args(name = "None")]
+    fn from_pandas(
+        &mut self,
+        data: &PyAny,
+        name: Option<&str>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let table = PyModule::import(py, "pyarrow")?
+            .getattr("Table")?
+            .call_method1("from_pandas", (data,))?;
+        self.from_arrow_table(table, name, py)
+    }
 
-        self.ctx
-            .register_table(&*name, Arc::new(table))
-            .map_err(DataFusionError::from)?;
-        let table = wait_for_future(py, self._table(&name)).map_err(DataFusionError::from)?;
+    /// Creates a DataFrame from a list of dictionaries, one per row,
+    /// registering it as `name` if given.
+    #[allow(clippy::wrong_self_convention)]
+    #[args(name = "None")]
+    fn from_pylist(
+        &mut self,
+        data: &PyAny,
+        name: Option<&str>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let table = PyModule::import(py, "pyarrow")?
+            .getattr("Table")?
+            .call_method1("from_pylist", (data,))?;
+        self.from_arrow_table(table, name, py)
+    }
 
-        Ok(PyDataFrame::new(table))
+    /// Creates a DataFrame from a dictionary of columns, registering it as
+    /// `name` if given.
+    #[allow(clippy::wrong_self_convention)]
+    #[args(name = "None")]
+    fn from_pydict(
+        &mut self,
+        data: &PyAny,
+        name: Option<&str>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let table = PyModule::import(py, "pyarrow")?
+            .getattr("Table")?
+            .call_method1("from_pydict", (data,))?;
+        self.from_arrow_table(table, name, py)
     }
 
     fn register_table(&mut self, name: &str, table: &PyTable) -> PyResult<()> {
@@ -137,6 +257,42 @@ impl PySessionContext {
         Ok(())
     }
 
+    /// Returns whether the table exists. An unknown catalog or schema
+    /// yields `False` rather than an error.
+    fn table_exists(&self, name: &str) -> PyResult<bool> {
+        let state = self.ctx.state();
+        let defaults = &state.config().options().catalog;
+        let table_ref = TableReference::from(name)
+            .resolve(&defaults.default_catalog, &defaults.default_schema);
+        let schema = self
+            .ctx
+            .catalog(&table_ref.catalog)
+            .and_then(|catalog| catalog.schema(&table_ref.schema));
+        if schema.is_none() {
+            return Ok(false);
+        }
+
+        let exists = self.ctx.table_exist(name).map_err(DataFusionError::from)?;
+        Ok(exists)
+    }
+
+    /// Registers an object store, so that paths starting with
+    /// `{scheme}://{host}` are read through it. For cloud stores a `host`
+    /// of `None` stands for the bucket name.
+    fn register_object_store(
+        &mut self,
+        scheme: &str,
+        host: Option<&str>,
+        store: StorageContexts,
+    ) -> PyResult<()> {
+        let (store, bucket) = store.into_store();
+        let host = host.unwrap_or(&bucket);
+        self.ctx
+            .runtime_env()
+            .register_object_store(scheme, host, store);
+        Ok(())
+    }
+
     fn register_record_batches(
         &mut self,
         name: &str,
@@ -150,11 +306,14 @@ impl PySessionContext {
         Ok(())
     }
 
+    /// Registers the parquet files at `path` as a table. Hints about the sort
+    /// order of the files aren't supported by DataFusion 17.
     #[allow(clippy::too_many_arguments)]
     #[args(
         table_partition_cols = "vec![]",
         parquet_pruning = "true",
-        file_extension = "\".parquet\""
+        file_extension = "\".parquet\"",
+        skip_metadata = "true"
     )]
     fn register_parquet(
         &mut self,
@@ -163,24 +322,31 @@ impl PySessionContext {
         table_partition_cols: Vec<(String, PyDataType)>,
         parquet_pruning: bool,
         file_extension: &str,
+        skip_metadata: bool,
         py: Python,
     ) -> PyResult<()> {
-        let mut options = ParquetReadOptions::default()
-            .table_partition_cols(convert_table_partition_cols(table_partition_cols))
-            .parquet_pruning(parquet_pruning);
-        options.file_extension = file_extension;
+        let options = parquet_read_options(
+            table_partition_cols,
+            parquet_pruning,
+            file_extension,
+            skip_metadata,
+        );
         let result = self.ctx.register_parquet(name, path, options);
         wait_for_future(py, result).map_err(DataFusionError::from)?;
         Ok(())
     }
 
+    /// Registers the CSV files at `path` as a table. The quote and escape
+    /// characters can't be configured: DataFusion 17 always quotes with `"`
+    /// and doesn't support escaping.
     #[allow(clippy::too_many_arguments)]
     #[args(
         schema = "None",
         has_header = "true",
         delimiter = "\",\"",
         schema_infer_max_records = "1000",
-        file_extension = "\".csv\""
+        file_extension = "\".csv\"",
+        table_partition_cols = "vec![]"
     )]
     fn register_csv(
         &mut self,
@@ -191,24 +357,20 @@ impl PySessionContext {
         delimiter: &str,
         schema_infer_max_records: usize,
         file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
         py: Python,
     ) -> PyResult<()> {
         let path = path
             .to_str()
             .ok_or_else(|| PyValueError::new_err("Unable to convert path to a string"))?;
-        let delimiter = delimiter.as_bytes();
-        if delimiter.len() != 1 {
-            return Err(PyValueError::new_err(
-                "Delimiter must be a single character",
-            ));
-        }
-
-        let mut options = CsvReadOptions::new()
-            .has_header(has_header)
-            .delimiter(delimiter[0])
-            .schema_infer_max_records(schema_infer_max_records)
-            .file_extension(file_extension);
-        options.schema = schema.as_ref().map(|x| &x.0);
+        let options = csv_read_options(
+            schema.as_ref().map(|x| &x.0),
+            has_header,
+            delimiter,
+            schema_infer_max_records,
+            file_extension,
+            table_partition_cols,
+        )?;
 
         let result = self.ctx.register_csv(name, path, options);
         wait_for_future(py, result).map_err(DataFusionError::from)?;
@@ -216,6 +378,171 @@ impl PySessionContext {
         Ok(())
     }
 
+    /// Registers newline-delimited JSON files, where each line is a record
+    #[allow(clippy::too_many_arguments)]
+    #[args(
+        schema = "None",
+        schema_infer_max_records = "1000",
+        file_extension = "\".json\"",
+        table_partition_cols = "vec![]"
+    )]
+    fn register_json(
+        &mut self,
+        name: &str,
+        path: &str,
+        schema: Option<PyArrowType<Schema>>,
+        schema_infer_max_records: usize,
+        file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        py: Python,
+    ) -> PyResult<()> {
+        let options = json_read_options(
+            schema,
+            schema_infer_max_records,
+            file_extension,
+            table_partition_cols,
+        );
+        let result = self.ctx.register_json(name, path, options);
+        wait_for_future(py, result).map_err(DataFusionError::from)?;
+        Ok(())
+    }
+
+    #[args(
+        schema = "None",
+        file_extension = "\".avro\"",
+        table_partition_cols = "vec![]"
+    )]
+    fn register_avro(
+        &mut self,
+        name: &str,
+        path: &str,
+        schema: Option<PyArrowType<Schema>>,
+        file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        py: Python,
+    ) -> PyResult<()> {
+        let options = avro_read_options(schema, file_extension, table_partition_cols);
+        let result = self.ctx.register_avro(name, path, options);
+        wait_for_future(py, result).map_err(DataFusionError::from)?;
+        Ok(())
+    }
+
+    /// Creates a DataFrame reading the parquet files at `path`, taking the
+    /// same options as `register_parquet`.
+    #[args(
+        table_partition_cols = "vec![]",
+        parquet_pruning = "true",
+        file_extension = "\".parquet\"",
+        skip_metadata = "true"
+    )]
+    fn read_parquet(
+        &self,
+        path: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        parquet_pruning: bool,
+        file_extension: &str,
+        skip_metadata: bool,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let options = parquet_read_options(
+            table_partition_cols,
+            parquet_pruning,
+            file_extension,
+            skip_metadata,
+        );
+        let result = self.ctx.read_parquet(path, options);
+        let df = wait_for_future(py, result).map_err(DataFusionError::from)?;
+        Ok(PyDataFrame::new(df))
+    }
+
+    /// Creates a DataFrame reading the CSV files at `path`, taking the same
+    /// options as `register_csv`.
+    #[allow(clippy::too_many_arguments)]
+    #[args(
+        schema = "None",
+        has_header = "true",
+        delimiter = "\",\"",
+        schema_infer_max_records = "1000",
+        file_extension = "\".csv\"",
+        table_partition_cols = "vec![]"
+    )]
+    fn read_csv(
+        &self,
+        path: PathBuf,
+        schema: Option<PyArrowType<Schema>>,
+        has_header: bool,
+        delimiter: &str,
+        schema_infer_max_records: usize,
+        file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let path = path
+            .to_str()
+            .ok_or_else(|| PyValueError::new_err("Unable to convert path to a string"))?;
+        let options = csv_read_options(
+            schema.as_ref().map(|x| &x.0),
+            has_header,
+            delimiter,
+            schema_infer_max_records,
+            file_extension,
+            table_partition_cols,
+        )?;
+
+        let result = self.ctx.read_csv(path, options);
+        let df = wait_for_future(py, result).map_err(DataFusionError::from)?;
+        Ok(PyDataFrame::new(df))
+    }
+
+    /// Creates a DataFrame reading the newline-delimited JSON files at
+    /// `path`, taking the same options as `register_json`.
+    #[args(
+        schema = "None",
+        schema_infer_max_records = "1000",
+        file_extension = "\".json\"",
+        table_partition_cols = "vec![]"
+    )]
+    fn read_json(
+        &self,
+        path: &str,
+        schema: Option<PyArrowType<Schema>>,
+        schema_infer_max_records: usize,
+        file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let options = json_read_options(
+            schema,
+            schema_infer_max_records,
+            file_extension,
+            table_partition_cols,
+        );
+        let result = self.ctx.read_json(path, options);
+        let df = wait_for_future(py, result).map_err(DataFusionError::from)?;
+        Ok(PyDataFrame::new(df))
+    }
+
+    /// Creates a DataFrame reading the Avro files at `path`, taking the same
+    /// options as `register_avro`.
+    #[args(
+        schema = "None",
+        file_extension = "\".avro\"",
+        table_partition_cols = "vec![]"
+    )]
+    fn read_avro(
+        &self,
+        path: &str,
+        schema: Option<PyArrowType<Schema>>,
+        file_extension: &str,
+        table_partition_cols: Vec<(String, PyDataType)>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let options = avro_read_options(schema, file_extension, table_partition_cols);
+        let result = self.ctx.read_avro(path, options);
+        let df = wait_for_future(py, result).map_err(DataFusionError::from)?;
+        Ok(PyDataFrame::new(df))
+    }
+
     // Registers a PyArrow.Dataset
     fn register_dataset(&self, name: &str, dataset: &PyAny, py: Python) -> PyResult<()> {
         let table: Arc<dyn TableProvider> = Arc::new(Dataset::new(dataset, py)?);
@@ -227,11 +554,101 @@ impl PySessionContext {
         Ok(())
     }
 
-    fn register_udf(&mut self, udf: PyScalarUDF) -> PyResult<()> {
-        self.ctx.register_udf(udf.function);
+    /// Registers a python object implementing `schema()` and
+    /// `scan(projection, filters, limit)` as a table
+    fn register_table_provider(&self, name: &str, provider: &PyAny) -> PyResult<()> {
+        let table: Arc<dyn TableProvider> =
+            Arc::new(PyTableProviderAdapter::new(provider)?);
+
+        self.ctx
+            .register_table(name, table)
+            .map_err(DataFusionError::from)?;
+
         Ok(())
     }
 
+    /// Registers a scalar UDF, either given as a `ScalarUDF` or as a name
+    /// together with the python callable and its signature.
+    #[args(
+        func = "None",
+        input_types = "None",
+        return_type = "None",
+        volatility = "\"immutable\""
+    )]
+    fn register_udf(
+        &mut self,
+        udf: &PyAny,
+        func: Option<PyObject>,
+        input_types: Option<PyArrowType<Vec<DataType>>>,
+        return_type: Option<PyArrowType<DataType>>,
+        volatility: &str,
+    ) -> PyResult<PyScalarUDF> {
+        let udf = if let Ok(name) = udf.extract::<&str>() {
+            match (func, input_types, return_type) {
+                (Some(func), Some(input_types), Some(return_type)) => {
+                    PyScalarUDF::new(name, func, input_types, return_type, volatility)?
+                }
+                _ => {
+                    return Err(PyValueError::new_err(
+                        "func, input_types and return_type are required when \
+                         registering a UDF by name",
+                    ))
+                }
+            }
+        } else {
+            udf.extract::<PyScalarUDF>()?
+        };
+        self.ctx.register_udf(udf.function.clone());
+        Ok(udf)
+    }
+
+    /// Registers an aggregate UDF, either given as an `AggregateUDF` or as a
+    /// name together with the python accumulator class and its signature.
+    #[args(
+        accumulator = "None",
+        input_type = "None",
+        return_type = "None",
+        state_type = "None",
+        volatility = "\"immutable\""
+    )]
+    fn register_udaf(
+        &mut self,
+        udaf: &PyAny,
+        accumulator: Option<PyObject>,
+        input_type: Option<PyArrowType<DataType>>,
+        return_type: Option<PyArrowType<DataType>>,
+        state_type: Option<PyArrowType<Vec<DataType>>>,
+        volatility: &str,
+    ) -> PyResult<PyAggregateUDF> {
+        let udaf = if let Ok(name) = udaf.extract::<&str>() {
+            match (accumulator, input_type, return_type, state_type) {
+                (
+                    Some(accumulator),
+                    Some(input_type),
+                    Some(return_type),
+                    Some(state_type),
+                ) => PyAggregateUDF::new(
+                    name,
+                    accumulator,
+                    input_type,
+                    return_type,
+                    state_type,
+                    volatility,
+                )?,
+                _ => {
+                    return Err(PyValueError::new_err(
+                        "accumulator, input_type, return_type and state_type are \
+                         required when registering a UDAF by name",
+                    ))
+                }
+            }
+        } else {
+            udaf.extract::<PyAggregateUDF>()?
+        };
+        self.ctx.register_udaf(udaf.function.clone());
+        Ok(udaf)
+    }
+
     #[args(name = "\"datafusion\"")]
     fn catalog(&self, name: &str) -> PyResult<PyCatalog> {
         match self.ctx.catalog(name) {
@@ -260,16 +677,148 @@ impl PySessionContext {
 }
 
 impl PySessionContext {
+    fn with_config(config: SessionConfig) -> Self {
+        let ctx = SessionContext::with_config(config);
+        ctx.register_udf(named_struct_udf());
+        Self { ctx }
+    }
+
+    pub(crate) fn context(&self) -> &SessionContext {
+        &self.ctx
+    }
+
+    /// Registers the partitions as a `MemTable` and returns a DataFrame
+    /// scanning it. Without a `name`, a random unique one is generated.
+    fn _create_dataframe(
+        &mut self,
+        schema: SchemaRef,
+        partitions: Vec<Vec<RecordBatch>>,
+        name: Option<&str>,
+        py: Python,
+    ) -> PyResult<PyDataFrame> {
+        let table =
+            MemTable::try_new(schema, partitions).map_err(DataFusionError::from)?;
+
+        let name = match name {
+            Some(name) => name.to_owned(),
+            // generate a random (unique) name for this table
+            // table name cannot start with numeric digit
+            None => {
+                "c".to_owned()
+                    + Uuid::new_v4()
+                        .to_simple()
+                        .encode_lower(&mut Uuid::encode_buffer())
+            }
+        };
+
+        self.ctx
+            .register_table(&*name, Arc::new(table))
+            .map_err(DataFusionError::from)?;
+        let table =
+            wait_for_future(py, self._table(&name)).map_err(DataFusionError::from)?;
+
+        Ok(PyDataFrame::new(table))
+    }
+
     async fn _table(&self, name: &str) -> datafusion_common::Result<DataFrame> {
         self.ctx.table(name).await
     }
 }
 
-fn convert_table_partition_cols(
-    table_partition_cols: Vec<(String, PyDataType)>,
-) -> Vec<(String, DataType)> {
-    table_partition_cols
+/// Returns the highest `$n` placeholder of the plan, i.e. the number of
+/// parameters it expects
+fn max_placeholder_index(plan: &LogicalPlan) -> datafusion_common::Result<usize> {
+    struct PlaceholderVisitor(usize);
+
+    impl ExpressionVisitor for PlaceholderVisitor {
+        fn pre_visit(
+            mut self,
+            expr: &Expr,
+        ) -> datafusion_common::Result<Recursion<Self>> {
+            if let Expr::Placeholder { id, .. } = expr {
+                let index = id
+                    .strip_prefix('$')
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .ok_or_else(|| {
+                        InnerDataFusionError::Plan(format!(
+                            "Invalid placeholder `{}`",
+                            id
+                        ))
+                    })?;
+                self.0 = self.0.max(index);
+            }
+            // the plans of subqueries aren't visited as expressions
+            let subquery = match expr {
+                Expr::Exists { subquery, .. }
+                | Expr::InSubquery { subquery, .. }
+                | Expr::ScalarSubquery(subquery) => Some(subquery),
+                _ => None,
+            };
+            if let Some(subquery) = subquery {
+                self.0 = self.0.max(max_placeholder_index(&subquery.subquery)?);
+            }
+            Ok(Recursion::Continue(self))
+        }
+    }
+
+    let visitor = plan
+        .expressions()
         .iter()
-        .map(|(name, t)| (name.clone(), t.data_type.clone()))
-        .collect()
+        .try_fold(PlaceholderVisitor(0), |visitor, expr| expr.accept(visitor))?;
+    plan.inputs().into_iter().try_fold(visitor.0, |max, input| {
+        Ok(max.max(max_placeholder_index(input)?))
+    })
+}
+
+/// Binds `values` to the placeholders of the subqueries of `plan`, which
+/// `LogicalPlan::replace_params_with_values` leaves untouched
+// DataFusion takes the values as a `&Vec`
+#[allow(clippy::ptr_arg)]
+fn bind_subquery_params(
+    plan: &LogicalPlan,
+    values: &Vec<ScalarValue>,
+) -> datafusion_common::Result<LogicalPlan> {
+    struct SubqueryBinder<'a>(&'a Vec<ScalarValue>);
+
+    impl ExprRewriter for SubqueryBinder<'_> {
+        fn mutate(&mut self, expr: Expr) -> datafusion_common::Result<Expr> {
+            let values = self.0;
+            let bind = |subquery: Subquery| -> datafusion_common::Result<Subquery> {
+                let plan = bind_subquery_params(&subquery.subquery, values)?
+                    .replace_params_with_values(values)?;
+                Ok(Subquery {
+                    subquery: Arc::new(plan),
+                })
+            };
+            Ok(match expr {
+                Expr::Exists { subquery, negated } => Expr::Exists {
+                    subquery: bind(subquery)?,
+                    negated,
+                },
+                Expr::InSubquery {
+                    expr,
+                    subquery,
+                    negated,
+                } => Expr::InSubquery {
+                    expr,
+                    subquery: bind(subquery)?,
+                    negated,
+                },
+                Expr::ScalarSubquery(subquery) => Expr::ScalarSubquery(bind(subquery)?),
+                expr => expr,
+            })
+        }
+    }
+
+    let exprs = plan
+        .expressions()
+        .into_iter()
+        .map(|expr| expr.rewrite(&mut SubqueryBinder(values)))
+        .collect::<datafusion_common::Result<Vec<_>>>()?;
+    let inputs = plan
+        .inputs()
+        .into_iter()
+        .map(|input| bind_subquery_params(input, values))
+        .collect::<datafusion_common::Result<Vec<_>>>()?;
+    from_plan(plan, &exprs, &inputs)
 }
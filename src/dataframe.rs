@@ -15,17 +15,105 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::utils::wait_for_future;
+use crate::logical_plan::PyLogicalPlan;
+use crate::metrics::PyMetricsSet;
+use crate::physical_plan::PyExecutionPlan;
+use crate::record_batch::PyRecordBatchStream;
+use crate::substrait::serialize_plan;
+use crate::utils::{parse_compression, wait_for_future};
 use crate::{errors::DataFusionError, expression::PyExpr};
-use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::array::{ArrayRef, Int64Array, StringArray};
+use datafusion::arrow::compute::concat_batches;
+use datafusion::arrow::csv::WriterBuilder;
+use datafusion::arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use datafusion::arrow::error::ArrowError;
 use datafusion::arrow::pyarrow::{PyArrowConvert, PyArrowException, PyArrowType};
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::arrow::util::display::array_value_to_string;
 use datafusion::arrow::util::pretty;
 use datafusion::dataframe::DataFrame;
-use datafusion::logical_expr::JoinType;
-use pyo3::exceptions::PyTypeError;
+use datafusion::logical_expr::{count, lit, Expr, ExprSchemable, JoinType, Partitioning};
+use datafusion::parquet::file::properties::WriterProperties;
+use datafusion::physical_plan::collect;
+use futures::StreamExt;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::PyTuple;
+use pyo3::types::{PyBytes, PyDict, PyTuple};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use tokio::runtime::{Handle, Runtime};
+use tokio::task;
+
+static DISPLAY_MAX_ROWS: AtomicUsize = AtomicUsize::new(10);
+static DISPLAY_MAX_WIDTH: AtomicUsize = AtomicUsize::new(40);
+
+fn truncate(value: String, max_width: usize) -> String {
+    if value.chars().count() <= max_width {
+        value
+    } else {
+        let mut truncated: String =
+            value.chars().take(max_width.saturating_sub(3)).collect();
+        truncated.push_str("...");
+        truncated
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// DataFusion doesn't validate the number of partitions, repartitioning
+/// into zero partitions would only fail (with a panic) once executed
+fn check_partitions(num: usize) -> PyResult<()> {
+    if num == 0 {
+        return Err(PyValueError::new_err("num must be positive"));
+    }
+    Ok(())
+}
+
+/// A column added by `with_column` that isn't projected yet. The columns
+/// form a list from the most recent one back, so that chaining
+/// `with_column` shares the columns added before instead of copying them.
+struct PendingColumn {
+    name: String,
+    expr: Expr,
+    previous: Option<Arc<PendingColumn>>,
+}
+
+/// Adds or replaces `columns` in a single projection. A column can be given
+/// more than once, it then keeps the position of its first occurrence and
+/// the expression of its last one.
+fn project_columns(
+    df: DataFrame,
+    columns: Vec<(String, Expr)>,
+) -> datafusion_common::Result<DataFrame> {
+    let mut projection = df
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| (field.name().clone(), Expr::Column(field.qualified_column())))
+        .collect::<Vec<_>>();
+    for (name, expr) in columns {
+        let expr = expr.alias(&name);
+        let mut replaced = false;
+        for (_, existing) in projection.iter_mut().filter(|(n, _)| *n == name) {
+            *existing = expr.clone();
+            replaced = true;
+        }
+        if !replaced {
+            projection.push((name, expr));
+        }
+    }
+    df.select(projection.into_iter().map(|(_, expr)| expr).collect())
+}
 
 /// A PyDataFrame is a representation of a logical plan and an API to compose statements.
 /// Use it to build a plan and `.collect()` to execute the plan and collect the result.
@@ -34,12 +122,172 @@ use std::sync::Arc;
 #[derive(Clone)]
 pub(crate) struct PyDataFrame {
     df: Arc<DataFrame>,
+    /// The columns added by `with_column` since `df` was built. They are
+    /// projected all at once when the `DataFrame` is used, instead of adding
+    /// a full-width projection per call.
+    pending: Option<Arc<PendingColumn>>,
+    /// `df` with the pending columns projected, once it was needed
+    projected: Arc<GILOnceCell<DataFrame>>,
 }
 
 impl PyDataFrame {
     /// creates a new PyDataFrame
     pub fn new(df: DataFrame) -> Self {
-        Self { df: Arc::new(df) }
+        Self {
+            df: Arc::new(df),
+            pending: None,
+            projected: Arc::new(GILOnceCell::new()),
+        }
+    }
+
+    /// Returns the `DataFrame`, with the columns added by `with_column`. They
+    /// are projected on first use only, the result is kept for later calls.
+    pub(crate) fn dataframe(&self) -> Result<&DataFrame, DataFusionError> {
+        if self.pending.is_none() {
+            return Ok(self.df.as_ref());
+        }
+
+        Python::with_gil(|py| {
+            if let Some(df) = self.projected.get(py) {
+                return Ok(df);
+            }
+            let mut columns = Vec::new();
+            let mut pending = self.pending.as_deref();
+            while let Some(column) = pending {
+                columns.push((column.name.clone(), column.expr.clone()));
+                pending = column.previous.as_deref();
+            }
+            columns.reverse();
+            let df = project_columns(self.df.as_ref().clone(), columns)?;
+            Ok(self.projected.get_or_init(py, || df))
+        })
+    }
+
+    /// Whether `expr` reads a column added by `with_column` that isn't
+    /// projected yet
+    fn reads_pending_column(&self, expr: &Expr) -> bool {
+        let names = match expr.to_columns() {
+            Ok(columns) => columns
+                .into_iter()
+                .map(|column| column.name)
+                .collect::<HashSet<_>>(),
+            // be conservative and project the pending columns first
+            Err(_) => return self.pending.is_some(),
+        };
+        let mut pending = self.pending.as_deref();
+        while let Some(column) = pending {
+            if names.contains(&column.name) {
+                return true;
+            }
+            pending = column.previous.as_deref();
+        }
+        false
+    }
+
+    /// Writes one CSV file per output partition into the directory `path`.
+    /// This mirrors DataFusion's `DataFrame::write_csv` but allows to omit
+    /// the header row.
+    async fn write_csv_partitions(
+        df: DataFrame,
+        path: &str,
+        header: bool,
+    ) -> datafusion_common::Result<()> {
+        let task_ctx = Arc::new(df.task_ctx());
+        let plan = df.create_physical_plan().await?;
+        fs::create_dir(path)?;
+
+        let partitions = plan.output_partitioning().partition_count();
+        let handle = Handle::current();
+        let writes = (0..partitions).map(|i| {
+            let plan = plan.clone();
+            let task_ctx = task_ctx.clone();
+            let file_path = Path::new(path).join(format!("part-{}.csv", i));
+            let handle = handle.clone();
+            // the CSV writer does blocking I/O, so every partition is written
+            // from its own blocking thread, which pulls the batches from the
+            // runtime
+            task::spawn_blocking(move || {
+                let file = fs::File::create(file_path)?;
+                let mut writer = WriterBuilder::new().has_headers(header).build(file);
+                let mut stream = plan.execute(i, task_ctx)?;
+                while let Some(batch) = handle.block_on(stream.next()) {
+                    writer.write(&batch?)?;
+                }
+                Ok::<_, datafusion_common::DataFusionError>(())
+            })
+        });
+        let results = futures::future::try_join_all(writes).await.map_err(|err| {
+            datafusion_common::DataFusionError::Execution(err.to_string())
+        })?;
+        results.into_iter().collect()
+    }
+
+    async fn count_rows(df: DataFrame) -> datafusion_common::Result<usize> {
+        let batches = df.aggregate(vec![], vec![count(lit(1))])?.collect().await?;
+        let rows = batches
+            .first()
+            .and_then(|batch| batch.column(0).as_any().downcast_ref::<Int64Array>())
+            .map(|counts| counts.value(0))
+            .unwrap_or(0);
+        Ok(rows as usize)
+    }
+
+    /// Collects at most `DISPLAY_MAX_ROWS` rows, with every value formatted
+    /// as a string truncated to `DISPLAY_MAX_WIDTH` characters. Also returns
+    /// whether the result has more rows.
+    fn preview(&self, py: Python) -> PyResult<(RecordBatch, bool)> {
+        let max_rows = DISPLAY_MAX_ROWS.load(Ordering::Relaxed);
+        let max_width = DISPLAY_MAX_WIDTH.load(Ordering::Relaxed);
+
+        let df = self.dataframe()?.clone();
+        let schema: SchemaRef = Arc::new(df.schema().into());
+        let batches = wait_for_future(py, df.limit(0, Some(max_rows + 1))?.collect())?;
+        let batch = match batches.first() {
+            Some(first) => concat_batches(&first.schema(), &batches)
+                .map_err(DataFusionError::from)?,
+            None => RecordBatch::new_empty(schema.clone()),
+        };
+        let rows = batch.num_rows().min(max_rows);
+
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| Field::new(field.name(), DataType::Utf8, true))
+            .collect();
+        let columns = batch
+            .columns()
+            .iter()
+            .map(|column| {
+                let values = (0..rows)
+                    .map(|row| {
+                        array_value_to_string(column, row)
+                            .map(|value| truncate(value, max_width))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Arc::new(StringArray::from(values)) as ArrayRef)
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()
+            .map_err(DataFusionError::from)?;
+        let preview = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+            .map_err(DataFusionError::from)?;
+        Ok((preview, batch.num_rows() > max_rows))
+    }
+
+    /// Converts the collected batches into a `pyarrow.Table`, using the
+    /// schema of the plan when there are no batches
+    fn to_table(&self, batches: Vec<RecordBatch>, py: Python) -> PyResult<PyObject> {
+        let batches = batches
+            .into_iter()
+            .map(|rb| rb.to_pyarrow(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        let table_class = PyModule::import(py, "pyarrow")?.getattr("Table")?;
+        let table = if batches.is_empty() {
+            let schema = Schema::from(self.dataframe()?.schema()).to_pyarrow(py)?;
+            table_class.call_method1("from_batches", (batches, schema))?
+        } else {
+            table_class.call_method1("from_batches", (batches,))?
+        };
+        Ok(table.into())
     }
 }
 
@@ -64,50 +312,168 @@ impl PyDataFrame {
         })
     }
 
+    /// Shows the schema and a preview of the first rows
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let (preview, has_more) = self.preview(py)?;
+        let fields = self
+            .dataframe()?
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| format!("{}: {}", field.name(), field.data_type()))
+            .collect::<Vec<_>>();
+        let table = pretty::pretty_format_batches(&[preview])
+            .map_err(|err| PyArrowException::new_err(err.to_string()))?;
+        let more = if has_more { "\n..." } else { "" };
+        Ok(format!(
+            "DataFrame[{}]\n{}{}",
+            fields.join(", "),
+            table,
+            more
+        ))
+    }
+
+    /// Renders a preview of the first rows as an HTML table, used by Jupyter
+    fn _repr_html_(&self, py: Python) -> PyResult<String> {
+        let (preview, has_more) = self.preview(py)?;
+        let mut html = String::from("<table border=\"1\">\n<tr>");
+        for field in preview.schema().fields() {
+            html.push_str(&format!("<th>{}</th>", escape_html(field.name())));
+        }
+        html.push_str("</tr>\n");
+        for row in 0..preview.num_rows() {
+            html.push_str("<tr>");
+            for column in preview.columns() {
+                let value = array_value_to_string(column, row)
+                    .map_err(|err| PyArrowException::new_err(err.to_string()))?;
+                html.push_str(&format!("<td>{}</td>", escape_html(&value)));
+            }
+            html.push_str("</tr>\n");
+        }
+        if has_more {
+            html.push_str("<tr>");
+            html.push_str(&"<td>...</td>".repeat(preview.num_columns()));
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</table>");
+        Ok(html)
+    }
+
+    /// Sets how many rows `__repr__` and `_repr_html_` preview, and how many
+    /// characters of each value they show.
+    #[staticmethod]
+    #[args(max_rows = "10", max_width = "40")]
+    fn set_display_options(max_rows: usize, max_width: usize) {
+        DISPLAY_MAX_ROWS.store(max_rows, Ordering::Relaxed);
+        DISPLAY_MAX_WIDTH.store(max_width, Ordering::Relaxed);
+    }
+
     /// Returns the schema from the logical plan
-    fn schema(&self) -> PyArrowType<Schema> {
-        PyArrowType(self.df.schema().into())
+    fn schema(&self) -> PyResult<PyArrowType<Schema>> {
+        Ok(PyArrowType(self.dataframe()?.schema().into()))
     }
 
     #[args(args = "*")]
     fn select_columns(&self, args: Vec<&str>) -> PyResult<Self> {
-        let df = self.df.as_ref().clone().select_columns(&args)?;
+        let df = self.dataframe()?.clone().select_columns(&args)?;
         Ok(Self::new(df))
     }
 
     #[args(args = "*")]
     fn select(&self, args: Vec<PyExpr>) -> PyResult<Self> {
         let expr = args.into_iter().map(|e| e.into()).collect();
-        let df = self.df.as_ref().clone().select(expr)?;
+        let df = self.dataframe()?.clone().select(expr)?;
         Ok(Self::new(df))
     }
 
     fn filter(&self, predicate: PyExpr) -> PyResult<Self> {
-        let df = self.df.as_ref().clone().filter(predicate.into())?;
+        let df = self.dataframe()?.clone().filter(predicate.into())?;
         Ok(Self::new(df))
     }
 
+    /// Adds or replaces the column `name`. The columns added by chained
+    /// calls are projected together once the `DataFrame` is used, unless
+    /// `expr` reads a column added by a previous call.
     fn with_column(&self, name: &str, expr: PyExpr) -> PyResult<Self> {
-        let df = self.df.as_ref().clone().with_column(name, expr.into())?;
+        let expr: Expr = expr.into();
+        let base = if self.reads_pending_column(&expr) {
+            Self::new(self.dataframe()?.clone())
+        } else {
+            self.clone()
+        };
+        // fail early on unknown columns and mismatched types
+        expr.get_type(base.df.schema())?;
+
+        Ok(Self {
+            df: base.df,
+            pending: Some(Arc::new(PendingColumn {
+                name: name.to_string(),
+                expr,
+                previous: base.pending,
+            })),
+            projected: Arc::new(GILOnceCell::new()),
+        })
+    }
+
+    /// Adds or replaces several columns at once, given as keyword arguments
+    /// mapping the column names to expressions. Unlike chaining
+    /// `with_column`, all the expressions are evaluated against the columns
+    /// of this `DataFrame`.
+    #[args(exprs = "**")]
+    fn with_columns(&self, exprs: Option<&PyDict>) -> PyResult<Self> {
+        let new_columns = match exprs {
+            Some(exprs) => exprs
+                .iter()
+                .map(|(name, expr)| {
+                    Ok((name.extract::<String>()?, expr.extract::<PyExpr>()?.expr))
+                })
+                .collect::<PyResult<Vec<_>>>()?,
+            None => vec![],
+        };
+
+        let df = project_columns(self.dataframe()?.clone(), new_columns)?;
         Ok(Self::new(df))
     }
 
     fn aggregate(&self, group_by: Vec<PyExpr>, aggs: Vec<PyExpr>) -> PyResult<Self> {
         let group_by = group_by.into_iter().map(|e| e.into()).collect();
         let aggs = aggs.into_iter().map(|e| e.into()).collect();
-        let df = self.df.as_ref().clone().aggregate(group_by, aggs)?;
+        let df = self.dataframe()?.clone().aggregate(group_by, aggs)?;
         Ok(Self::new(df))
     }
 
     #[args(exprs = "*")]
     fn sort(&self, exprs: Vec<PyExpr>) -> PyResult<Self> {
         let exprs = exprs.into_iter().map(|e| e.into()).collect();
-        let df = self.df.as_ref().clone().sort(exprs)?;
+        let df = self.dataframe()?.clone().sort(exprs)?;
         Ok(Self::new(df))
     }
 
     fn limit(&self, count: usize) -> PyResult<Self> {
-        let df = self.df.as_ref().clone().limit(0, Some(count))?;
+        let df = self.dataframe()?.clone().limit(0, Some(count))?;
+        Ok(Self::new(df))
+    }
+
+    /// Repartition the `DataFrame` into `num` partitions, distributing
+    /// batches in a round-robin fashion.
+    fn repartition(&self, num: usize) -> PyResult<Self> {
+        check_partitions(num)?;
+        let df = self
+            .dataframe()?
+            .clone()
+            .repartition(Partitioning::RoundRobinBatch(num))?;
+        Ok(Self::new(df))
+    }
+
+    /// Repartition the `DataFrame` into `num` partitions, using the hash of
+    /// `exprs` to decide which partition each row goes to.
+    fn repartition_by_hash(&self, exprs: Vec<PyExpr>, num: usize) -> PyResult<Self> {
+        check_partitions(num)?;
+        let exprs = exprs.into_iter().map(|e| e.into()).collect();
+        let df = self
+            .dataframe()?
+            .clone()
+            .repartition(Partitioning::Hash(exprs, num))?;
         Ok(Self::new(df))
     }
 
@@ -115,16 +481,152 @@ impl PyDataFrame {
     /// Unless some order is specified in the plan, there is no
     /// guarantee of the order of the result.
     fn collect(&self, py: Python) -> PyResult<Vec<PyObject>> {
-        let batches = wait_for_future(py, self.df.as_ref().clone().collect())?;
+        let batches = wait_for_future(py, self.dataframe()?.clone().collect())?;
         // cannot use PyResult<Vec<RecordBatch>> return type due to
         // https://github.com/PyO3/pyo3/issues/1813
         batches.into_iter().map(|rb| rb.to_pyarrow(py)).collect()
     }
 
+    /// Executes the plan like `collect`, additionally returning the metrics
+    /// recorded by each operator, such as the rows it produced and the time
+    /// it took.
+    fn collect_with_metrics(
+        &self,
+        py: Python,
+    ) -> PyResult<(Vec<PyObject>, PyMetricsSet)> {
+        let df = self.dataframe()?.clone();
+        let (batches, plan) = wait_for_future(py, async move {
+            let task_ctx = Arc::new(df.task_ctx());
+            let plan = df.create_physical_plan().await?;
+            let batches = collect(plan.clone(), task_ctx).await?;
+            Ok::<_, datafusion_common::DataFusionError>((batches, plan))
+        })
+        .map_err(DataFusionError::from)?;
+        let batches = batches
+            .into_iter()
+            .map(|rb| rb.to_pyarrow(py))
+            .collect::<PyResult<_>>()?;
+        Ok((batches, PyMetricsSet::from_plan(plan.as_ref())))
+    }
+
+    /// Executes the plan, returning the result as a `pyarrow.Table`.
+    fn to_arrow_table(&self, py: Python) -> PyResult<PyObject> {
+        let batches = wait_for_future(py, self.dataframe()?.clone().collect())?;
+        self.to_table(batches, py)
+    }
+
+    /// Executes the plan, returning the result as a `pandas.DataFrame`.
+    fn to_pandas(&self, py: Python) -> PyResult<PyObject> {
+        let table = self.to_arrow_table(py)?;
+        table.call_method0(py, "to_pandas")
+    }
+
+    /// Executes the plan, returning the result as a dictionary of column
+    /// names to lists of values.
+    fn to_pydict(&self, py: Python) -> PyResult<PyObject> {
+        let table = self.to_arrow_table(py)?;
+        table.call_method0(py, "to_pydict")
+    }
+
+    /// Executes the plan, returning the result as a `polars.DataFrame`.
+    fn to_polars(&self, py: Python) -> PyResult<PyObject> {
+        let table = self.to_arrow_table(py)?;
+        let polars = PyModule::import(py, "polars")?;
+        Ok(polars.call_method1("from_arrow", (table,))?.into())
+    }
+
+    /// Executes the plan, returning the number of rows.
+    fn count(&self, py: Python) -> PyResult<usize> {
+        let df = self.dataframe()?.clone();
+        Ok(wait_for_future(py, Self::count_rows(df))?)
+    }
+
+    /// Executes the plan, returning its first `n` rows as a `pyarrow.Table`.
+    #[args(n = "5")]
+    fn head(&self, n: usize, py: Python) -> PyResult<PyObject> {
+        let df = self.dataframe()?.clone().limit(0, Some(n))?;
+        let batches = wait_for_future(py, df.collect())?;
+        self.to_table(batches, py)
+    }
+
+    /// Executes the plan, returning its last `n` rows as a `pyarrow.Table`.
+    /// As for `head`, the rows are only well defined if the plan is sorted.
+    #[args(n = "5")]
+    fn tail(&self, n: usize, py: Python) -> PyResult<PyObject> {
+        let batches = wait_for_future(py, self.dataframe()?.clone().collect())?;
+
+        // the plan is executed once, keeping the last rows of the result
+        let mut remaining = n;
+        let mut tail = Vec::new();
+        for batch in batches.into_iter().rev() {
+            if remaining == 0 {
+                break;
+            }
+            let rows = batch.num_rows().min(remaining);
+            tail.push(batch.slice(batch.num_rows() - rows, rows));
+            remaining -= rows;
+        }
+        tail.reverse();
+        self.to_table(tail, py)
+    }
+
+    /// Executes the plan, returning a stream of `RecordBatch`es.
+    /// Batches are computed incrementally while the stream is consumed,
+    /// so the result doesn't need to fit in memory.
+    fn execute_stream(&self, py: Python) -> PyResult<PyRecordBatchStream> {
+        let runtime =
+            Runtime::new().map_err(|err| DataFusionError::Common(err.to_string()))?;
+        let df = self.dataframe()?.clone();
+        let stream = py
+            .allow_threads(|| runtime.block_on(df.execute_stream()))
+            .map_err(DataFusionError::from)?;
+        Ok(PyRecordBatchStream::new(stream, runtime))
+    }
+
+    /// Writes the result as parquet files, one per partition, into the
+    /// directory `path`.
+    #[args(compression = "\"uncompressed\"", row_group_size = "None")]
+    fn write_parquet(
+        &self,
+        path: &str,
+        compression: &str,
+        row_group_size: Option<usize>,
+        py: Python,
+    ) -> PyResult<()> {
+        let mut properties =
+            WriterProperties::builder().set_compression(parse_compression(compression)?);
+        if let Some(row_group_size) = row_group_size {
+            properties = properties.set_max_row_group_size(row_group_size);
+        }
+
+        let df = self.dataframe()?.clone();
+        wait_for_future(py, df.write_parquet(path, Some(properties.build())))
+            .map_err(DataFusionError::from)?;
+        Ok(())
+    }
+
+    /// Writes the result as CSV files, one per partition, into the
+    /// directory `path`.
+    #[args(header = "true")]
+    fn write_csv(&self, path: &str, header: bool, py: Python) -> PyResult<()> {
+        let df = self.dataframe()?.clone();
+        wait_for_future(py, Self::write_csv_partitions(df, path, header))
+            .map_err(DataFusionError::from)?;
+        Ok(())
+    }
+
+    /// Writes the result as newline-delimited JSON files, one per partition,
+    /// into the directory `path`.
+    fn write_json(&self, path: &str, py: Python) -> PyResult<()> {
+        let df = self.dataframe()?.clone();
+        wait_for_future(py, df.write_json(path)).map_err(DataFusionError::from)?;
+        Ok(())
+    }
+
     /// Print the result, 20 lines by default
     #[args(num = "20")]
     fn show(&self, py: Python, num: usize) -> PyResult<()> {
-        let df = self.df.as_ref().clone().limit(0, Some(num))?;
+        let df = self.dataframe()?.clone().limit(0, Some(num))?;
         let batches = wait_for_future(py, df.collect())?;
         pretty::print_batches(&batches)
             .map_err(|err| PyArrowException::new_err(err.to_string()))
@@ -153,8 +655,8 @@ impl PyDataFrame {
             }
         };
 
-        let df = self.df.as_ref().clone().join(
-            right.df.as_ref().clone(),
+        let df = self.dataframe()?.clone().join(
+            right.dataframe()?.clone(),
             join_type,
             &join_keys.0,
             &join_keys.1,
@@ -163,10 +665,86 @@ impl PyDataFrame {
         Ok(Self::new(df))
     }
 
+    /// Returns the unoptimized logical plan of this `DataFrame`
+    fn logical_plan(&self) -> PyResult<PyLogicalPlan> {
+        Ok(self.dataframe()?.logical_plan().clone().into())
+    }
+
+    /// Returns the logical plan of this `DataFrame` after optimization
+    fn optimized_logical_plan(&self) -> PyResult<PyLogicalPlan> {
+        Ok(self.dataframe()?.clone().into_optimized_plan()?.into())
+    }
+
+    /// Returns the physical plan that executes this `DataFrame`
+    fn execution_plan(&self, py: Python) -> PyResult<PyExecutionPlan> {
+        let plan = wait_for_future(py, self.dataframe()?.clone().create_physical_plan())?;
+        Ok(plan.into())
+    }
+
+    /// Serializes the logical plan to Substrait protobuf bytes
+    fn to_substrait(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = serialize_plan(self.dataframe()?.logical_plan())?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+
+    /// Calculate the union of two `DataFrame`s, preserving duplicate rows
+    /// unless `distinct` is set. The two `DataFrame`s must have exactly
+    /// the same schema.
+    #[args(distinct = false)]
+    fn union(&self, py_df: PyDataFrame, distinct: bool) -> PyResult<Self> {
+        let df = if distinct {
+            self.dataframe()?
+                .clone()
+                .union_distinct(py_df.dataframe()?.clone())?
+        } else {
+            self.dataframe()?
+                .clone()
+                .union(py_df.dataframe()?.clone())?
+        };
+        Ok(Self::new(df))
+    }
+
+    /// Calculate the distinct union of two `DataFrame`s. The two
+    /// `DataFrame`s must have exactly the same schema.
+    fn union_distinct(&self, py_df: PyDataFrame) -> PyResult<Self> {
+        let df = self
+            .dataframe()?
+            .clone()
+            .union_distinct(py_df.dataframe()?.clone())?;
+        Ok(Self::new(df))
+    }
+
+    /// Calculate the intersection of two `DataFrame`s. The two
+    /// `DataFrame`s must have exactly the same schema.
+    fn intersect(&self, py_df: PyDataFrame) -> PyResult<Self> {
+        let df = self
+            .dataframe()?
+            .clone()
+            .intersect(py_df.dataframe()?.clone())?;
+        Ok(Self::new(df))
+    }
+
+    /// Calculate the exception of two `DataFrame`s, i.e. the rows of this
+    /// `DataFrame` that are not in `py_df`. The two `DataFrame`s must have
+    /// exactly the same schema.
+    fn except_(&self, py_df: PyDataFrame) -> PyResult<Self> {
+        let df = self
+            .dataframe()?
+            .clone()
+            .except(py_df.dataframe()?.clone())?;
+        Ok(Self::new(df))
+    }
+
+    /// Filter out duplicate rows
+    fn distinct(&self) -> PyResult<Self> {
+        let df = self.dataframe()?.clone().distinct()?;
+        Ok(Self::new(df))
+    }
+
     /// Print the explain output to stdout
     #[args(verbose = false, analyze = false)]
     fn explain(&self, py: Python, verbose: bool, analyze: bool) -> PyResult<()> {
-        let df = self.df.as_ref().clone().explain(verbose, analyze)?;
+        let df = self.dataframe()?.clone().explain(verbose, analyze)?;
         let batches = wait_for_future(py, df.collect())?;
         pretty::print_batches(&batches)
             .map_err(|err| PyArrowException::new_err(err.to_string()))
@@ -180,7 +758,7 @@ impl PyDataFrame {
         verbose: bool,
         analyze: bool,
     ) -> PyResult<String> {
-        let df = self.df.as_ref().clone().explain(verbose, analyze)?;
+        let df = self.dataframe()?.clone().explain(verbose, analyze)?;
         let batches = wait_for_future(py, df.collect())?;
         let display = pretty::pretty_format_batches(&batches)
             .map_err(|err| PyArrowException::new_err(err.to_string()))?;
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use datafusion::logical_expr::expr::Cast;
+use datafusion::logical_expr::expr::{Between, Cast, Like, TryCast};
 use pyo3::{basic::CompareOp, prelude::*};
 use std::convert::{From, Into};
 
@@ -24,6 +24,37 @@ use datafusion::arrow::pyarrow::PyArrowType;
 use datafusion::logical_expr::{col, lit, Expr, GetIndexedField};
 use datafusion::scalar::ScalarValue;
 
+use crate::utils::py_obj_to_scalar_value;
+
+/// A python value usable as a literal: either a pyarrow scalar or any value
+/// `pyarrow.scalar` accepts, such as datetimes, decimals, bytes, lists and
+/// `None`
+#[derive(Debug, Clone)]
+pub(crate) struct PyScalarValue(pub(crate) ScalarValue);
+
+impl<'source> FromPyObject<'source> for PyScalarValue {
+    fn extract(value: &'source PyAny) -> PyResult<Self> {
+        Ok(PyScalarValue(py_obj_to_scalar_value(value.py(), value)?))
+    }
+}
+
+/// The right-hand side of an operator, plain python values are turned into
+/// literals
+#[derive(FromPyObject)]
+pub(crate) enum PyOperand {
+    Expr(PyExpr),
+    Value(PyScalarValue),
+}
+
+impl From<PyOperand> for Expr {
+    fn from(operand: PyOperand) -> Expr {
+        match operand {
+            PyOperand::Expr(expr) => expr.expr,
+            PyOperand::Value(value) => lit(value.0),
+        }
+    }
+}
+
 /// An PyExpr that can be used on a DataFrame
 #[pyclass(name = "Expression", module = "ballista", subclass)]
 #[derive(Debug, Clone)]
@@ -45,14 +76,15 @@ impl From<Expr> for PyExpr {
 
 #[pymethods]
 impl PyExpr {
-    fn __richcmp__(&self, other: PyExpr, op: CompareOp) -> PyExpr {
+    fn __richcmp__(&self, other: PyOperand, op: CompareOp) -> PyExpr {
+        let other = Expr::from(other);
         let expr = match op {
-            CompareOp::Lt => self.expr.clone().lt(other.expr),
-            CompareOp::Le => self.expr.clone().lt_eq(other.expr),
-            CompareOp::Eq => self.expr.clone().eq(other.expr),
-            CompareOp::Ne => self.expr.clone().not_eq(other.expr),
-            CompareOp::Gt => self.expr.clone().gt(other.expr),
-            CompareOp::Ge => self.expr.clone().gt_eq(other.expr),
+            CompareOp::Lt => self.expr.clone().lt(other),
+            CompareOp::Le => self.expr.clone().lt_eq(other),
+            CompareOp::Eq => self.expr.clone().eq(other),
+            CompareOp::Ne => self.expr.clone().not_eq(other),
+            CompareOp::Gt => self.expr.clone().gt(other),
+            CompareOp::Ge => self.expr.clone().gt_eq(other),
         };
         expr.into()
     }
@@ -61,49 +93,88 @@ impl PyExpr {
         Ok(format!("{}", self.expr))
     }
 
-    fn __add__(&self, rhs: PyExpr) -> PyResult<PyExpr> {
-        Ok((self.expr.clone() + rhs.expr).into())
+    fn __add__(&self, rhs: PyOperand) -> PyResult<PyExpr> {
+        Ok((self.expr.clone() + Expr::from(rhs)).into())
+    }
+
+    fn __sub__(&self, rhs: PyOperand) -> PyResult<PyExpr> {
+        Ok((self.expr.clone() - Expr::from(rhs)).into())
+    }
+
+    fn __truediv__(&self, rhs: PyOperand) -> PyResult<PyExpr> {
+        Ok((self.expr.clone() / Expr::from(rhs)).into())
+    }
+
+    fn __mul__(&self, rhs: PyOperand) -> PyResult<PyExpr> {
+        Ok((self.expr.clone() * Expr::from(rhs)).into())
+    }
+
+    fn __mod__(&self, rhs: PyOperand) -> PyResult<PyExpr> {
+        Ok(self.expr.clone().modulus(Expr::from(rhs)).into())
+    }
+
+    fn __and__(&self, rhs: PyOperand) -> PyResult<PyExpr> {
+        Ok(self.expr.clone().and(Expr::from(rhs)).into())
     }
 
-    fn __sub__(&self, rhs: PyExpr) -> PyResult<PyExpr> {
-        Ok((self.expr.clone() - rhs.expr).into())
+    fn __or__(&self, rhs: PyOperand) -> PyResult<PyExpr> {
+        Ok(self.expr.clone().or(Expr::from(rhs)).into())
     }
 
-    fn __truediv__(&self, rhs: PyExpr) -> PyResult<PyExpr> {
-        Ok((self.expr.clone() / rhs.expr).into())
+    fn __radd__(&self, lhs: PyOperand) -> PyResult<PyExpr> {
+        Ok((Expr::from(lhs) + self.expr.clone()).into())
     }
 
-    fn __mul__(&self, rhs: PyExpr) -> PyResult<PyExpr> {
-        Ok((self.expr.clone() * rhs.expr).into())
+    fn __rsub__(&self, lhs: PyOperand) -> PyResult<PyExpr> {
+        Ok((Expr::from(lhs) - self.expr.clone()).into())
     }
 
-    fn __mod__(&self, rhs: PyExpr) -> PyResult<PyExpr> {
-        Ok(self.expr.clone().modulus(rhs.expr).into())
+    fn __rtruediv__(&self, lhs: PyOperand) -> PyResult<PyExpr> {
+        Ok((Expr::from(lhs) / self.expr.clone()).into())
     }
 
-    fn __and__(&self, rhs: PyExpr) -> PyResult<PyExpr> {
-        Ok(self.expr.clone().and(rhs.expr).into())
+    fn __rmul__(&self, lhs: PyOperand) -> PyResult<PyExpr> {
+        Ok((Expr::from(lhs) * self.expr.clone()).into())
     }
 
-    fn __or__(&self, rhs: PyExpr) -> PyResult<PyExpr> {
-        Ok(self.expr.clone().or(rhs.expr).into())
+    fn __rmod__(&self, lhs: PyOperand) -> PyResult<PyExpr> {
+        Ok(Expr::from(lhs).modulus(self.expr.clone()).into())
+    }
+
+    fn __rand__(&self, lhs: PyOperand) -> PyResult<PyExpr> {
+        Ok(Expr::from(lhs).and(self.expr.clone()).into())
+    }
+
+    fn __ror__(&self, lhs: PyOperand) -> PyResult<PyExpr> {
+        Ok(Expr::from(lhs).or(self.expr.clone()).into())
     }
 
     fn __invert__(&self) -> PyResult<PyExpr> {
         Ok(self.expr.clone().not().into())
     }
 
-    fn __getitem__(&self, key: &str) -> PyResult<PyExpr> {
+    /// Accesses a struct field by name or a list element by its zero-based
+    /// index
+    fn __getitem__(&self, key: PyScalarValue) -> PyResult<PyExpr> {
         Ok(Expr::GetIndexedField(GetIndexedField {
             expr: Box::new(self.expr.clone()),
-            key: ScalarValue::Utf8(Some(key.to_string())),
+            key: key.0,
         })
         .into())
     }
 
+    /// Accesses the field `name` of a struct
+    pub fn get_field(&self, name: &str) -> PyExpr {
+        Expr::GetIndexedField(GetIndexedField {
+            expr: Box::new(self.expr.clone()),
+            key: ScalarValue::Utf8(Some(name.to_string())),
+        })
+        .into()
+    }
+
     #[staticmethod]
-    pub fn literal(value: ScalarValue) -> PyExpr {
-        lit(value).into()
+    pub fn literal(value: PyScalarValue) -> PyExpr {
+        lit(value.0).into()
     }
 
     #[staticmethod]
@@ -126,6 +197,55 @@ impl PyExpr {
         self.expr.clone().is_null().into()
     }
 
+    pub fn is_not_null(&self) -> PyExpr {
+        self.expr.clone().is_not_null().into()
+    }
+
+    pub fn is_true(&self) -> PyExpr {
+        self.expr.clone().is_true().into()
+    }
+
+    pub fn is_false(&self) -> PyExpr {
+        self.expr.clone().is_false().into()
+    }
+
+    /// Whether the value is within `low` and `high`, both inclusive
+    #[args(negated = false)]
+    pub fn between(&self, low: PyOperand, high: PyOperand, negated: bool) -> PyExpr {
+        Expr::Between(Between::new(
+            Box::new(self.expr.clone()),
+            negated,
+            Box::new(low.into()),
+            Box::new(high.into()),
+        ))
+        .into()
+    }
+
+    /// SQL `LIKE`, `%` matches any sequence of characters and `_` any single one
+    #[args(negated = false)]
+    pub fn like(&self, pattern: PyOperand, negated: bool) -> PyExpr {
+        Expr::Like(self.like_expr(pattern, negated)).into()
+    }
+
+    /// Case insensitive version of `like`
+    #[args(negated = false)]
+    pub fn ilike(&self, pattern: PyOperand, negated: bool) -> PyExpr {
+        Expr::ILike(self.like_expr(pattern, negated)).into()
+    }
+
+    /// SQL `SIMILAR TO`, matching against a regular expression
+    #[args(negated = false)]
+    pub fn similar_to(&self, pattern: PyOperand, negated: bool) -> PyExpr {
+        Expr::SimilarTo(self.like_expr(pattern, negated)).into()
+    }
+
+    /// Whether the value is one of `values`, like SQL `IN`
+    #[args(negated = false)]
+    pub fn in_(&self, values: Vec<PyOperand>, negated: bool) -> PyExpr {
+        let values = values.into_iter().map(Expr::from).collect();
+        self.expr.clone().in_list(values, negated).into()
+    }
+
     pub fn cast(&self, to: PyArrowType<DataType>) -> PyExpr {
         // self.expr.cast_to() requires DFSchema to validate that the cast
         // is supported, omit that for now
@@ -135,4 +255,25 @@ impl PyExpr {
         });
         expr.into()
     }
+
+    /// Like `cast`, but returns null instead of failing when a value
+    /// can't be converted
+    pub fn try_cast(&self, to: PyArrowType<DataType>) -> PyExpr {
+        let expr = Expr::TryCast(TryCast {
+            expr: Box::new(self.expr.clone()),
+            data_type: to.0,
+        });
+        expr.into()
+    }
+}
+
+impl PyExpr {
+    fn like_expr(&self, pattern: PyOperand, negated: bool) -> Like {
+        Like::new(
+            negated,
+            Box::new(self.expr.clone()),
+            Box::new(pattern.into()),
+            None,
+        )
+    }
 }
@@ -0,0 +1,156 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use ballista_core::serde::protobuf::{
+    action::ActionType, Action, FetchPartition, PartitionLocation,
+};
+use prost::Message;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::errors::BallistaError;
+
+/// Where to fetch one partition of a job's result with an Arrow Flight
+/// `DoGet`, e.g. using `pyarrow.flight`.
+#[pyclass(name = "FlightEndpoint", module = "ballista.flight", subclass)]
+#[derive(Debug, Clone)]
+pub(crate) struct PyFlightEndpoint {
+    #[pyo3(get)]
+    host: String,
+    #[pyo3(get)]
+    port: u16,
+    ticket: Vec<u8>,
+}
+
+impl PyFlightEndpoint {
+    /// Builds the endpoint of the executor holding the partition at
+    /// `location`. The ticket is the same `FetchPartition` action Ballista's
+    /// own client sends.
+    pub(crate) fn try_from_location(
+        location: &PartitionLocation,
+    ) -> Result<Self, BallistaError> {
+        let metadata = location.executor_meta.as_ref().ok_or_else(|| {
+            BallistaError::Common("Received empty executor metadata".to_string())
+        })?;
+        let partition_id = location.partition_id.as_ref().ok_or_else(|| {
+            BallistaError::Common("Received empty partition id".to_string())
+        })?;
+
+        let action = Action {
+            action_type: Some(ActionType::FetchPartition(FetchPartition {
+                job_id: partition_id.job_id.clone(),
+                stage_id: partition_id.stage_id,
+                partition_id: partition_id.partition_id,
+                path: location.path.clone(),
+                host: metadata.host.clone(),
+                port: metadata.port,
+            })),
+            settings: vec![],
+        };
+        Ok(Self {
+            host: metadata.host.clone(),
+            port: metadata.port as u16,
+            ticket: action.encode_to_vec(),
+        })
+    }
+}
+
+#[pymethods]
+impl PyFlightEndpoint {
+    #[new]
+    fn new(host: String, port: u16, ticket: &PyBytes) -> Self {
+        Self {
+            host,
+            port,
+            ticket: ticket.as_bytes().to_vec(),
+        }
+    }
+
+    /// The location to connect a Flight client to
+    #[getter]
+    fn uri(&self) -> String {
+        format!("grpc://{}:{}", self.host, self.port)
+    }
+
+    /// The opaque ticket to pass to `DoGet`
+    #[getter]
+    fn ticket(&self, py: Python) -> PyObject {
+        PyBytes::new(py, &self.ticket).into()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FlightEndpoint({})", self.uri())
+    }
+}
+
+pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyFlightEndpoint>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ballista_core::serde::protobuf::{
+        action::ActionType, Action, ExecutorMetadata, PartitionId, PartitionLocation,
+    };
+    use prost::Message;
+
+    use super::PyFlightEndpoint;
+
+    #[test]
+    fn endpoint_from_location() {
+        let location = PartitionLocation {
+            partition_id: Some(PartitionId {
+                job_id: "job".to_string(),
+                stage_id: 2,
+                partition_id: 3,
+            }),
+            executor_meta: Some(ExecutorMetadata {
+                id: "executor".to_string(),
+                host: "executor-host".to_string(),
+                port: 50051,
+                ..Default::default()
+            }),
+            path: "/tmp/job/2/3".to_string(),
+            ..Default::default()
+        };
+
+        let endpoint = PyFlightEndpoint::try_from_location(&location).unwrap();
+        assert_eq!(endpoint.host, "executor-host");
+        assert_eq!(endpoint.port, 50051);
+        assert_eq!(endpoint.uri(), "grpc://executor-host:50051");
+
+        let action = Action::decode(endpoint.ticket.as_slice()).unwrap();
+        match action.action_type {
+            Some(ActionType::FetchPartition(fetch)) => {
+                assert_eq!(fetch.job_id, "job");
+                assert_eq!(fetch.stage_id, 2);
+                assert_eq!(fetch.partition_id, 3);
+                assert_eq!(fetch.path, "/tmp/job/2/3");
+                assert_eq!(fetch.host, "executor-host");
+                assert_eq!(fetch.port, 50051);
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+    }
+
+    #[test]
+    fn endpoint_from_incomplete_location() {
+        let location = PartitionLocation::default();
+        assert!(PyFlightEndpoint::try_from_location(&location).is_err());
+    }
+}
@@ -15,13 +15,33 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
 use datafusion::prelude::lit;
-use pyo3::{prelude::*, wrap_pyfunction};
+use pyo3::exceptions::PyValueError;
+use pyo3::{prelude::*, types::PyTuple, wrap_pyfunction};
 
 use datafusion::physical_plan::aggregates::AggregateFunction;
 use datafusion_expr::{self, BuiltinScalarFunction, window_function::find_df_window_func};
+use datafusion_expr::window_frame::WindowFrame;
+use datafusion_expr::{
+    ColumnarValue, ReturnTypeFunction, ScalarFunctionImplementation, ScalarUDF,
+    Signature, TypeSignature, Volatility,
+};
+
+use datafusion::arrow::array::{ArrayRef, StructArray};
+
+use datafusion::arrow::datatypes::{DataType, Field};
+use datafusion::arrow::pyarrow::PyArrowType;
+use datafusion::error::DataFusionError as InnerDataFusionError;
+use datafusion::scalar::ScalarValue;
 
+use crate::conditional_expr::PyCaseBuilder;
+use crate::errors::DataFusionError;
 use crate::expression::PyExpr;
+use crate::udaf::PyAggregateUDF;
+use crate::udf::PyScalarUDF;
+use crate::window_frame::PyWindowFrame;
 
 #[pyfunction]
 fn in_list(expr: PyExpr, value: Vec<PyExpr>, negated: bool) -> PyExpr {
@@ -33,6 +53,18 @@ fn in_list(expr: PyExpr, value: Vec<PyExpr>, negated: bool) -> PyExpr {
     .into()
 }
 
+/// Creates a CASE expression comparing `expr` with the value of each branch
+#[pyfunction]
+fn case(expr: PyExpr) -> PyCaseBuilder {
+    datafusion_expr::case(expr.expr).into()
+}
+
+/// Creates a CASE expression whose first branch returns `then` when `when` is true
+#[pyfunction]
+fn when(when: PyExpr, then: PyExpr) -> PyCaseBuilder {
+    datafusion_expr::when(when.expr, then.expr).into()
+}
+
 /// Computes a binary hash of the given data. type is the algorithm to use.
 /// Standard algorithms are md5, sha224, sha256, sha384, sha512, blake2s, blake2b, and blake3.
 #[pyfunction(value, method)]
@@ -59,6 +91,131 @@ fn concat_ws(sep: String, args: Vec<PyExpr>) -> PyResult<PyExpr> {
     Ok(datafusion_expr::expr_fn::concat_ws(lit(sep), args).into())
 }
 
+/// Creates a struct whose fields are the given expressions, named `c0`, `c1`, ...
+#[pyfunction(args = "*")]
+#[pyo3(name = "struct")]
+fn struct_(args: Vec<PyExpr>) -> PyExpr {
+    datafusion_expr::Expr::ScalarFunction {
+        fun: BuiltinScalarFunction::Struct,
+        args: args.into_iter().map(|e| e.into()).collect(),
+    }
+    .into()
+}
+
+/// Creates a struct from alternating field names and expressions, e.g.
+/// `named_struct("a", col("x"), "b", col("y"))`.
+/// It is implemented by a UDF that Ballista executors don't know, so plans
+/// using it can only be executed locally by a `SessionContext`.
+#[pyfunction(args = "*")]
+fn named_struct(args: &PyTuple) -> PyResult<PyExpr> {
+    if args.is_empty() {
+        return Err(PyValueError::new_err(
+            "named_struct expects at least one field",
+        ));
+    }
+    if args.len() % 2 != 0 {
+        return Err(DataFusionError::Common(
+            "named_struct expects alternating field names and expressions".to_string(),
+        )
+        .into());
+    }
+    // the names travel in the type of a null struct literal, as the return
+    // type of a UDF can only depend on the types of its arguments
+    let mut fields = Vec::with_capacity(args.len() / 2);
+    let mut exprs = Vec::with_capacity(args.len() / 2 + 1);
+    for pair in args.as_slice().chunks(2) {
+        let name = pair[0].extract::<String>()?;
+        fields.push(Field::new(name.as_str(), DataType::Null, true));
+        exprs.push(pair[1].extract::<PyExpr>()?.expr);
+    }
+    exprs.insert(0, lit(ScalarValue::Struct(None, Box::new(fields))));
+
+    Ok(named_struct_udf().call(exprs).into())
+}
+
+/// The UDF behind `named_struct`. Its first argument is a struct whose field
+/// names are those of the result, the other ones are the field values. It is
+/// registered on every `SessionContext`, so that plans using it can be
+/// serialized and planned again by name. The name is an internal one, as SQL
+/// queries can't pass the field names that way.
+pub(crate) fn named_struct_udf() -> ScalarUDF {
+    let return_type: ReturnTypeFunction =
+        Arc::new(|types: &[DataType]| match types.split_first() {
+            Some((DataType::Struct(names), values)) if names.len() == values.len() => {
+                let fields = names
+                    .iter()
+                    .zip(values)
+                    .map(|(name, data_type)| {
+                        Field::new(name.name(), data_type.clone(), true)
+                    })
+                    .collect();
+                Ok(Arc::new(DataType::Struct(fields)))
+            }
+            _ => Err(InnerDataFusionError::Plan(
+                "named_struct expects alternating field names and expressions"
+                    .to_string(),
+            )),
+        });
+    let fun: ScalarFunctionImplementation = Arc::new(|args: &[ColumnarValue]| {
+        let (names, values) = match args.split_first() {
+            Some((ColumnarValue::Scalar(ScalarValue::Struct(_, names)), values)) => {
+                (names, values)
+            }
+            _ => {
+                return Err(InnerDataFusionError::Internal(
+                    "named_struct expects the field names as first argument".to_string(),
+                ))
+            }
+        };
+
+        // a struct of scalars is a scalar too, so that it is broadcast
+        let scalars: Vec<ScalarValue> = values
+            .iter()
+            .filter_map(|value| match value {
+                ColumnarValue::Scalar(scalar) => Some(scalar.clone()),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect();
+        if scalars.len() == values.len() {
+            let fields = names
+                .iter()
+                .zip(&scalars)
+                .map(|(name, scalar)| {
+                    Field::new(name.name(), scalar.get_datatype(), true)
+                })
+                .collect();
+            return Ok(ColumnarValue::Scalar(ScalarValue::Struct(
+                Some(scalars),
+                Box::new(fields),
+            )));
+        }
+
+        let num_rows = values
+            .iter()
+            .find_map(|value| match value {
+                ColumnarValue::Array(array) => Some(array.len()),
+                ColumnarValue::Scalar(_) => None,
+            })
+            .unwrap_or(1);
+        let columns: Vec<(Field, ArrayRef)> = names
+            .iter()
+            .zip(values)
+            .map(|(name, value)| {
+                let array = value.clone().into_array(num_rows);
+                let field = Field::new(name.name(), array.data_type().clone(), true);
+                (field, array)
+            })
+            .collect();
+        Ok(ColumnarValue::Array(Arc::new(StructArray::from(columns))))
+    });
+    ScalarUDF::new(
+        "__ballista_named_struct",
+        &Signature::new(TypeSignature::VariadicAny, Volatility::Immutable),
+        &return_type,
+        &fun,
+    )
+}
+
 /// Creates a new Sort expression
 #[pyfunction]
 fn order_by(
@@ -88,16 +245,21 @@ fn alias(expr: PyExpr, name: &str) -> PyResult<PyExpr> {
     })
 }
 
-/// Creates a new Window function expression
+/// Creates a new Window function expression.
+/// Without an explicit `window_frame`, the frame spans the whole partition,
+/// or up to the current row when `order_by` is given.
 #[pyfunction]
 fn window(
     name: &str,
     args: Vec<PyExpr>,
     partition_by: Option<Vec<PyExpr>>,
     order_by: Option<Vec<PyExpr>>,
+    window_frame: Option<PyWindowFrame>,
 ) -> PyResult<PyExpr> {
     let fun = find_df_window_func(name).unwrap();
-    let has_order_by = order_by.is_some();
+    let window_frame = window_frame
+        .map(|frame| frame.into())
+        .unwrap_or_else(|| WindowFrame::new(order_by.is_some()));
     Ok(PyExpr {
         expr: datafusion_expr::expr::Expr::WindowFunction (
             datafusion_expr::expr::WindowFunction {
@@ -113,11 +275,68 @@ fn window(
                     .into_iter()
                     .map(|x| x.expr)
                     .collect::<Vec<_>>(),
-                window_frame: datafusion_expr::window_frame::WindowFrame::new(has_order_by),
+                window_frame,
             }),
     })
 }
 
+/// Creates a new scalar UDF from a python function over pyarrow arrays.
+/// The returned UDF can be called with expressions to build a new expression.
+#[pyfunction(
+    func,
+    input_types,
+    return_type,
+    volatility = "\"immutable\"",
+    name = "None"
+)]
+fn udf(
+    py: Python,
+    func: PyObject,
+    input_types: PyArrowType<Vec<DataType>>,
+    return_type: PyArrowType<DataType>,
+    volatility: &str,
+    name: Option<String>,
+) -> PyResult<PyScalarUDF> {
+    let name = match name {
+        Some(name) => name,
+        None => func.getattr(py, "__qualname__")?.extract(py)?,
+    };
+    PyScalarUDF::new(&name, func, input_types, return_type, volatility)
+}
+
+/// Creates a new aggregate UDF from a python accumulator class.
+/// The class must implement `state`, `update`, `merge` and `evaluate`.
+#[pyfunction(
+    accumulator,
+    input_type,
+    return_type,
+    state_type,
+    volatility = "\"immutable\"",
+    name = "None"
+)]
+fn udaf(
+    py: Python,
+    accumulator: PyObject,
+    input_type: PyArrowType<DataType>,
+    return_type: PyArrowType<DataType>,
+    state_type: PyArrowType<Vec<DataType>>,
+    volatility: &str,
+    name: Option<String>,
+) -> PyResult<PyAggregateUDF> {
+    let name = match name {
+        Some(name) => name,
+        None => accumulator.getattr(py, "__qualname__")?.extract(py)?,
+    };
+    PyAggregateUDF::new(
+        &name,
+        accumulator,
+        input_type,
+        return_type,
+        state_type,
+        volatility,
+    )
+}
+
 macro_rules! scalar_function {
     ($NAME: ident, $FUNC: ident) => {
         scalar_function!($NAME, $FUNC, stringify!($NAME));
@@ -141,14 +360,18 @@ macro_rules! aggregate_function {
     };
     ($NAME: ident, $FUNC: ident, $DOC: expr) => {
         #[doc = $DOC]
-        #[pyfunction(args = "*", distinct = "false")]
-        fn $NAME(args: Vec<PyExpr>, distinct: bool) -> PyExpr {
+        #[doc = ""]
+        #[doc = "Only aggregates the `distinct` values when set, and only the rows"]
+        #[doc = "matching `filter` when given. Ordering the input isn't supported,"]
+        #[doc = "as DataFusion 17 has no ORDER BY for aggregate functions."]
+        #[pyfunction(args = "*", distinct = "false", filter = "None")]
+        fn $NAME(args: Vec<PyExpr>, distinct: bool, filter: Option<PyExpr>) -> PyExpr {
             let expr = datafusion_expr::Expr::AggregateFunction({
                 datafusion_expr::expr::AggregateFunction {
                     fun: AggregateFunction::$FUNC,
                     args: args.into_iter().map(|e| e.into()).collect(),
                     distinct,
-                    filter: None,
+                    filter: filter.map(|f| Box::new(f.into())),
                 }
             });
             expr.into()
@@ -267,8 +490,7 @@ scalar_function!(upper, Upper, "Converts the string to all upper case.");
 scalar_function!(make_array, MakeArray);
 scalar_function!(array, MakeArray);
 scalar_function!(nullif, NullIf);
-//scalar_function!(uuid, Uuid);
-//scalar_function!(struct, Struct);
+scalar_function!(uuid, Uuid);
 scalar_function!(from_unixtime, FromUnixtime);
 scalar_function!(arrow_typeof, ArrowTypeof);
 scalar_function!(random, Random);
@@ -279,13 +501,55 @@ aggregate_function!(max, Max);
 aggregate_function!(min, Min);
 aggregate_function!(sum, Sum);
 aggregate_function!(approx_distinct, ApproxDistinct);
+aggregate_function!(approx_median, ApproxMedian);
+aggregate_function!(
+    approx_percentile_cont,
+    ApproxPercentileCont,
+    "Approximates the given percentile of the input, e.g. `approx_percentile_cont(col, lit(0.9))`."
+);
+aggregate_function!(
+    approx_percentile_cont_with_weight,
+    ApproxPercentileContWithWeight,
+    "Like `approx_percentile_cont`, with each input weighted by the second argument."
+);
+aggregate_function!(
+    array_agg,
+    ArrayAgg,
+    "Collects the input values into a list."
+);
+aggregate_function!(corr, Correlation);
+aggregate_function!(covar, Covariance, "Sample covariance of two columns.");
+aggregate_function!(
+    covariance,
+    Covariance,
+    "Sample covariance of two columns, the same as `covar`."
+);
+aggregate_function!(
+    covar_pop,
+    CovariancePop,
+    "Population covariance of two columns."
+);
+aggregate_function!(
+    grouping,
+    Grouping,
+    "Returns 1 if the argument is aggregated over in the current grouping set, 0 otherwise. DataFusion 17 can plan it but not execute it yet."
+);
+aggregate_function!(median, Median);
+aggregate_function!(stddev, Stddev, "Sample standard deviation.");
+aggregate_function!(stddev_pop, StddevPop, "Population standard deviation.");
+aggregate_function!(var_pop, VariancePop, "Population variance.");
+aggregate_function!(variance, Variance, "Sample variance.");
 
 pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(abs))?;
     m.add_wrapped(wrap_pyfunction!(acos))?;
     m.add_wrapped(wrap_pyfunction!(approx_distinct))?;
+    m.add_wrapped(wrap_pyfunction!(approx_median))?;
+    m.add_wrapped(wrap_pyfunction!(approx_percentile_cont))?;
+    m.add_wrapped(wrap_pyfunction!(approx_percentile_cont_with_weight))?;
     m.add_wrapped(wrap_pyfunction!(alias))?;
     m.add_wrapped(wrap_pyfunction!(array))?;
+    m.add_wrapped(wrap_pyfunction!(array_agg))?;
     m.add_wrapped(wrap_pyfunction!(arrow_typeof))?;
     m.add_wrapped(wrap_pyfunction!(ascii))?;
     m.add_wrapped(wrap_pyfunction!(asin))?;
@@ -294,6 +558,7 @@ pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(avg))?;
     m.add_wrapped(wrap_pyfunction!(bit_length))?;
     m.add_wrapped(wrap_pyfunction!(btrim))?;
+    m.add_wrapped(wrap_pyfunction!(case))?;
     m.add_wrapped(wrap_pyfunction!(ceil))?;
     m.add_wrapped(wrap_pyfunction!(character_length))?;
     m.add_wrapped(wrap_pyfunction!(chr))?;
@@ -301,8 +566,12 @@ pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(coalesce))?;
     m.add_wrapped(wrap_pyfunction!(concat_ws))?;
     m.add_wrapped(wrap_pyfunction!(concat))?;
+    m.add_wrapped(wrap_pyfunction!(corr))?;
     m.add_wrapped(wrap_pyfunction!(cos))?;
     m.add_wrapped(wrap_pyfunction!(count))?;
+    m.add_wrapped(wrap_pyfunction!(covar))?;
+    m.add_wrapped(wrap_pyfunction!(covar_pop))?;
+    m.add_wrapped(wrap_pyfunction!(covariance))?;
     m.add_wrapped(wrap_pyfunction!(current_date))?;
     m.add_wrapped(wrap_pyfunction!(current_time))?;
     m.add_wrapped(wrap_pyfunction!(date_bin))?;
@@ -314,6 +583,7 @@ pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(exp))?;
     m.add_wrapped(wrap_pyfunction!(floor))?;
     m.add_wrapped(wrap_pyfunction!(from_unixtime))?;
+    m.add_wrapped(wrap_pyfunction!(grouping))?;
     m.add_wrapped(wrap_pyfunction!(in_list))?;
     m.add_wrapped(wrap_pyfunction!(initcap))?;
     m.add_wrapped(wrap_pyfunction!(left))?;
@@ -328,8 +598,10 @@ pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(max))?;
     m.add_wrapped(wrap_pyfunction!(make_array))?;
     m.add_wrapped(wrap_pyfunction!(md5))?;
+    m.add_wrapped(wrap_pyfunction!(median))?;
     m.add_wrapped(wrap_pyfunction!(min))?;
     m.add_wrapped(wrap_pyfunction!(now))?;
+    m.add_wrapped(wrap_pyfunction!(named_struct))?;
     m.add_wrapped(wrap_pyfunction!(nullif))?;
     m.add_wrapped(wrap_pyfunction!(octet_length))?;
     m.add_wrapped(wrap_pyfunction!(order_by))?;
@@ -354,8 +626,10 @@ pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(split_part))?;
     m.add_wrapped(wrap_pyfunction!(sqrt))?;
     m.add_wrapped(wrap_pyfunction!(starts_with))?;
+    m.add_wrapped(wrap_pyfunction!(stddev))?;
+    m.add_wrapped(wrap_pyfunction!(stddev_pop))?;
     m.add_wrapped(wrap_pyfunction!(strpos))?;
-    //m.add_wrapped(wrap_pyfunction!(struct))?;
+    m.add_wrapped(wrap_pyfunction!(struct_))?;
     m.add_wrapped(wrap_pyfunction!(substr))?;
     m.add_wrapped(wrap_pyfunction!(sum))?;
     m.add_wrapped(wrap_pyfunction!(tan))?;
@@ -367,8 +641,13 @@ pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(translate))?;
     m.add_wrapped(wrap_pyfunction!(trim))?;
     m.add_wrapped(wrap_pyfunction!(trunc))?;
+    m.add_wrapped(wrap_pyfunction!(udaf))?;
+    m.add_wrapped(wrap_pyfunction!(udf))?;
     m.add_wrapped(wrap_pyfunction!(upper))?;
-    //m.add_wrapped(wrap_pyfunction!(uuid))?;
+    m.add_wrapped(wrap_pyfunction!(var_pop))?;
+    m.add_wrapped(wrap_pyfunction!(variance))?;
+    m.add_wrapped(wrap_pyfunction!(when))?;
+    m.add_wrapped(wrap_pyfunction!(uuid))?;
     m.add_wrapped(wrap_pyfunction!(window))?;
     Ok(())
 }
@@ -0,0 +1,337 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::future::Future;
+use std::time::Duration;
+
+use ballista::prelude::BallistaConfig;
+use ballista_core::client::BallistaClient;
+use ballista_core::serde::protobuf::{
+    execute_query_params::Query, job_status, scheduler_grpc_client::SchedulerGrpcClient,
+    CancelJobParams, ExecuteQueryParams, GetJobStatusParams, KeyValuePair,
+    PartitionLocation,
+};
+use ballista_core::serde::BallistaLogicalExtensionCodec;
+use datafusion::arrow::pyarrow::PyArrowConvert;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::dataframe::DataFrame;
+use datafusion_proto::bytes::logical_plan_to_bytes_with_extension_codec;
+use futures::TryStreamExt;
+use pyo3::exceptions::{PyNotImplementedError, PyValueError};
+use pyo3::prelude::*;
+use tokio::runtime::Runtime;
+use tonic::transport::Channel;
+
+use crate::errors::BallistaError;
+use crate::flight::PyFlightEndpoint;
+use crate::utils::wait_for_future;
+
+/// A job submitted to a Ballista scheduler, which can be polled, cancelled,
+/// or waited on while the Python thread stays responsive.
+///
+/// Jobs are submitted with `BallistaContext.submit`, a `DataFrame` doesn't
+/// know which scheduler to submit itself to. The scheduler doesn't report
+/// the progress of running jobs, so `progress` raises
+/// `NotImplementedError`.
+#[pyclass(name = "JobHandle", module = "ballista", subclass)]
+pub(crate) struct PyJobHandle {
+    job_id: String,
+    // the connection to the scheduler, kept open for the handle's lifetime
+    client: SchedulerGrpcClient<Channel>,
+    // the runtime the connection runs on, it must outlive the client
+    runtime: Runtime,
+}
+
+fn grpc_error<E: std::fmt::Display>(err: E) -> BallistaError {
+    BallistaError::Common(format!("Scheduler request failed: {}", err))
+}
+
+/// The name of a job status, as returned to Python
+fn status_name(status: &job_status::Status) -> &'static str {
+    match status {
+        job_status::Status::Queued(_) => "queued",
+        job_status::Status::Running(_) => "running",
+        job_status::Status::Failed(_) => "failed",
+        job_status::Status::Successful(_) => "successful",
+    }
+}
+
+/// Only jobs that didn't finish yet can be cancelled
+fn check_cancellable(
+    job_id: &str,
+    status: &job_status::Status,
+) -> Result<(), BallistaError> {
+    match status {
+        job_status::Status::Queued(_) | job_status::Status::Running(_) => Ok(()),
+        status => Err(BallistaError::Common(format!(
+            "Job {} can't be cancelled, it is already {}",
+            job_id,
+            status_name(status)
+        ))),
+    }
+}
+
+impl PyJobHandle {
+    /// Submits the optimized plan of `df` to the scheduler at
+    /// `scheduler_url`, without waiting for it to run.
+    pub(crate) fn submit(
+        py: Python,
+        scheduler_url: &str,
+        config: &BallistaConfig,
+        df: &DataFrame,
+    ) -> PyResult<Self> {
+        let plan = df
+            .clone()
+            .into_optimized_plan()
+            .map_err(BallistaError::from)?;
+        let codec = BallistaLogicalExtensionCodec::default();
+        let plan = logical_plan_to_bytes_with_extension_codec(&plan, &codec)
+            .map_err(BallistaError::from)?;
+        let settings = config
+            .settings()
+            .iter()
+            .map(|(key, value)| KeyValuePair {
+                key: key.clone(),
+                value: value.clone(),
+            })
+            .collect();
+        let params = ExecuteQueryParams {
+            query: Some(Query::LogicalPlan(plan.to_vec())),
+            settings,
+            optional_session_id: None,
+        };
+
+        let runtime =
+            Runtime::new().map_err(|err| BallistaError::Common(err.to_string()))?;
+        let url = scheduler_url.to_string();
+        let (client, job_id) = py.allow_threads(|| {
+            runtime.block_on(async move {
+                let mut client = SchedulerGrpcClient::connect(url)
+                    .await
+                    .map_err(grpc_error)?;
+                let result = client.execute_query(params).await.map_err(grpc_error)?;
+                Ok::<_, BallistaError>((client, result.into_inner().job_id))
+            })
+        })?;
+
+        Ok(Self {
+            job_id,
+            client,
+            runtime,
+        })
+    }
+
+    /// Runs `f` with the scheduler client, on the runtime of the handle and
+    /// with the GIL released
+    fn with_client<F, Fut, T>(&self, py: Python, f: F) -> Result<T, BallistaError>
+    where
+        F: FnOnce(SchedulerGrpcClient<Channel>) -> Fut + Send,
+        Fut: Future<Output = Result<T, BallistaError>>,
+        T: Send,
+    {
+        // gRPC clients share their connection when cloned
+        let (runtime, client) = (&self.runtime, self.client.clone());
+        py.allow_threads(|| runtime.block_on(f(client)))
+    }
+
+    fn fetch_status(&self, py: Python) -> PyResult<job_status::Status> {
+        let job_id = self.job_id.clone();
+        let status = self.with_client(py, |mut client| async move {
+            let params = GetJobStatusParams {
+                job_id: job_id.clone(),
+            };
+            let result = client.get_job_status(params).await.map_err(grpc_error)?;
+            result
+                .into_inner()
+                .status
+                .and_then(|status| status.status)
+                .ok_or_else(|| BallistaError::Common(format!("Unknown job {}", job_id)))
+        })?;
+        Ok(status)
+    }
+
+    /// Polls the status of the job until it finished, returning the
+    /// locations of its output partitions
+    fn wait(&self, poll_interval: f64, py: Python) -> PyResult<Vec<PartitionLocation>> {
+        if !poll_interval.is_finite() || poll_interval <= 0.0 {
+            return Err(PyValueError::new_err(
+                "poll_interval must be a positive number of seconds",
+            ));
+        }
+        let poll_interval = Duration::from_secs_f64(poll_interval);
+
+        loop {
+            match self.fetch_status(py)? {
+                job_status::Status::Queued(_) | job_status::Status::Running(_) => {
+                    py.allow_threads(|| std::thread::sleep(poll_interval));
+                    py.check_signals()?;
+                }
+                job_status::Status::Failed(failed) => {
+                    return Err(BallistaError::Common(format!(
+                        "Job {} failed: {}",
+                        self.job_id, failed.error
+                    ))
+                    .into())
+                }
+                job_status::Status::Successful(successful) => {
+                    return Ok(successful.partition_location)
+                }
+            }
+        }
+    }
+
+    /// Fetches the output partition at `location` from the executor that
+    /// produced it.
+    async fn fetch_partition(
+        location: PartitionLocation,
+    ) -> Result<Vec<RecordBatch>, BallistaError> {
+        let metadata = location.executor_meta.ok_or_else(|| {
+            BallistaError::Common("Received empty executor metadata".to_string())
+        })?;
+        let partition_id = location.partition_id.ok_or_else(|| {
+            BallistaError::Common("Received empty partition id".to_string())
+        })?;
+        let host = metadata.host.as_str();
+        let port = metadata.port as u16;
+
+        let mut client = BallistaClient::try_new(host, port).await?;
+        let stream = client
+            .fetch_partition(
+                &metadata.id,
+                &partition_id.into(),
+                &location.path,
+                host,
+                port,
+            )
+            .await?;
+        Ok(stream.try_collect().await?)
+    }
+}
+
+#[pymethods]
+impl PyJobHandle {
+    #[getter]
+    fn job_id(&self) -> &str {
+        &self.job_id
+    }
+
+    /// Returns the state of the job: "queued", "running", "failed" or
+    /// "successful"
+    fn status(&self, py: Python) -> PyResult<&'static str> {
+        Ok(status_name(&self.fetch_status(py)?))
+    }
+
+    /// Asks the scheduler to cancel the job, returns whether it was cancelled.
+    /// Raises if the job already finished.
+    fn cancel(&self, py: Python) -> PyResult<bool> {
+        check_cancellable(&self.job_id, &self.fetch_status(py)?)?;
+
+        let params = CancelJobParams {
+            job_id: self.job_id.clone(),
+        };
+        let cancelled = self.with_client(py, |mut client| async move {
+            let result = client.cancel_job(params).await.map_err(grpc_error)?;
+            Ok::<_, BallistaError>(result.into_inner().cancelled)
+        })?;
+        Ok(cancelled)
+    }
+
+    /// Not supported, the scheduler only reports whether a job is queued,
+    /// running or finished, not how many of its tasks completed
+    fn progress(&self) -> PyResult<f64> {
+        Err(PyNotImplementedError::new_err(
+            "The scheduler doesn't report the progress of running jobs, \
+             use status() instead",
+        ))
+    }
+
+    /// Waits for the job to finish, polling its status every `poll_interval`
+    /// seconds, and returns its output as a list of `RecordBatch`es.
+    /// Interrupting the wait (e.g. with Ctrl-C) leaves the job running.
+    #[args(poll_interval = "0.1")]
+    fn result(&self, poll_interval: f64, py: Python) -> PyResult<Vec<PyObject>> {
+        let locations = self.wait(poll_interval, py)?;
+        let batches = wait_for_future(py, async move {
+            let partitions = locations.into_iter().map(Self::fetch_partition);
+            futures::future::try_join_all(partitions).await
+        })?;
+        batches
+            .into_iter()
+            .flatten()
+            .map(|batch| batch.to_pyarrow(py))
+            .collect()
+    }
+
+    /// Waits for the job to finish like `result`, but instead of fetching
+    /// the output returns the Arrow Flight endpoints serving each partition
+    /// of it, so that other Flight clients can fetch it.
+    #[args(poll_interval = "0.1")]
+    fn flight_endpoints(
+        &self,
+        poll_interval: f64,
+        py: Python,
+    ) -> PyResult<Vec<PyFlightEndpoint>> {
+        let locations = self.wait(poll_interval, py)?;
+        Ok(locations
+            .iter()
+            .map(PyFlightEndpoint::try_from_location)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("JobHandle({})", self.job_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ballista_core::serde::protobuf::{
+        job_status::Status, FailedJob, QueuedJob, RunningJob, SuccessfulJob,
+    };
+
+    use super::{check_cancellable, status_name};
+
+    #[test]
+    fn status_names() {
+        let statuses = [
+            (Status::Queued(QueuedJob::default()), "queued"),
+            (Status::Running(RunningJob::default()), "running"),
+            (Status::Failed(FailedJob::default()), "failed"),
+            (Status::Successful(SuccessfulJob::default()), "successful"),
+        ];
+        for (status, name) in statuses {
+            assert_eq!(status_name(&status), name);
+        }
+    }
+
+    #[test]
+    fn only_unfinished_jobs_are_cancellable() {
+        let queued = Status::Queued(QueuedJob::default());
+        let running = Status::Running(RunningJob::default());
+        assert!(check_cancellable("job", &queued).is_ok());
+        assert!(check_cancellable("job", &running).is_ok());
+
+        let successful = Status::Successful(SuccessfulJob::default());
+        let failed = Status::Failed(FailedJob::default());
+        let err = check_cancellable("job", &successful).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Job job can't be cancelled, it is already successful"
+        );
+        assert!(check_cancellable("job", &failed).is_err());
+    }
+}
@@ -24,6 +24,10 @@ mod ballista_context;
 #[allow(clippy::borrow_deref_ref)]
 pub mod catalog;
 #[allow(clippy::borrow_deref_ref)]
+mod conditional_expr;
+#[allow(clippy::borrow_deref_ref)]
+mod config;
+#[allow(clippy::borrow_deref_ref)]
 mod context;
 #[allow(clippy::borrow_deref_ref)]
 mod dataframe;
@@ -35,13 +39,32 @@ pub mod errors;
 #[allow(clippy::borrow_deref_ref)]
 mod expression;
 #[allow(clippy::borrow_deref_ref)]
+mod flight;
+#[allow(clippy::borrow_deref_ref)]
 mod functions;
+#[allow(clippy::borrow_deref_ref)]
+mod job;
+#[allow(clippy::borrow_deref_ref)]
+mod logical_plan;
+#[allow(clippy::borrow_deref_ref)]
+mod metrics;
+#[allow(clippy::borrow_deref_ref)]
+mod physical_plan;
 mod pyarrow_filter_expression;
 #[allow(clippy::borrow_deref_ref)]
+mod record_batch;
+#[allow(clippy::borrow_deref_ref)]
+mod store;
+#[allow(clippy::borrow_deref_ref)]
+mod substrait;
+mod table_provider;
+#[allow(clippy::borrow_deref_ref)]
 mod udaf;
 #[allow(clippy::borrow_deref_ref)]
 mod udf;
 pub mod utils;
+#[allow(clippy::borrow_deref_ref)]
+mod window_frame;
 
 #[cfg(feature = "mimalloc")]
 #[global_allocator]
@@ -58,16 +81,41 @@ fn _internal(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<catalog::PyDatabase>()?;
     m.add_class::<catalog::PyTable>()?;
     m.add_class::<ballista_context::PyBallistaContext>()?;
+    m.add_class::<conditional_expr::PyCaseBuilder>()?;
+    m.add_class::<config::PyBallistaConfig>()?;
+    m.add_class::<config::PySessionConfig>()?;
     m.add_class::<context::PySessionContext>()?;
     m.add_class::<dataframe::PyDataFrame>()?;
     m.add_class::<expression::PyExpr>()?;
+    m.add_class::<job::PyJobHandle>()?;
+    m.add_class::<logical_plan::PyLogicalPlan>()?;
+    m.add_class::<metrics::PyMetric>()?;
+    m.add_class::<metrics::PyMetricsSet>()?;
+    m.add_class::<physical_plan::PyExecutionPlan>()?;
+    m.add_class::<record_batch::PyRecordBatchStream>()?;
     m.add_class::<udf::PyScalarUDF>()?;
     m.add_class::<udaf::PyAggregateUDF>()?;
+    m.add_class::<window_frame::PyWindowFrame>()?;
 
     // Register the functions as a submodule
     let funcs = PyModule::new(py, "functions")?;
     functions::init_module(funcs)?;
     m.add_submodule(funcs)?;
 
+    // Register the object stores as a submodule
+    let store = PyModule::new(py, "object_store")?;
+    store::init_module(store)?;
+    m.add_submodule(store)?;
+
+    // Register the Arrow Flight endpoints as a submodule
+    let flight = PyModule::new(py, "flight")?;
+    flight::init_module(flight)?;
+    m.add_submodule(flight)?;
+
+    // Register the substrait (de)serialization as a submodule
+    let substrait = PyModule::new(py, "substrait")?;
+    substrait::init_module(substrait)?;
+    m.add_submodule(substrait)?;
+
     Ok(())
 }
@@ -0,0 +1,83 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use datafusion::logical_expr::LogicalPlan;
+use datafusion_proto::bytes::logical_plan_to_bytes;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::errors::DataFusionError;
+
+/// A logical plan that can be inspected from Python
+#[pyclass(name = "LogicalPlan", module = "ballista", subclass)]
+#[derive(Debug, Clone)]
+pub(crate) struct PyLogicalPlan {
+    plan: Arc<LogicalPlan>,
+}
+
+impl From<LogicalPlan> for PyLogicalPlan {
+    fn from(plan: LogicalPlan) -> PyLogicalPlan {
+        PyLogicalPlan {
+            plan: Arc::new(plan),
+        }
+    }
+}
+
+#[pymethods]
+impl PyLogicalPlan {
+    /// Returns the plans this plan directly depends on
+    fn inputs(&self) -> Vec<PyLogicalPlan> {
+        self.plan
+            .inputs()
+            .into_iter()
+            .map(|input| input.clone().into())
+            .collect()
+    }
+
+    /// Returns the plan tree as an indented string, one node per line
+    fn display_indent(&self) -> String {
+        format!("{}", self.plan.display_indent())
+    }
+
+    /// Returns the plan tree as an indented string, one node per line,
+    /// including the schema of each node
+    fn display_indent_schema(&self) -> String {
+        format!("{}", self.plan.display_indent_schema())
+    }
+
+    /// Returns the plan tree in the graphviz DOT format
+    fn display_graphviz(&self) -> String {
+        format!("{}", self.plan.display_graphviz())
+    }
+
+    /// Returns this node only, without its inputs
+    fn display(&self) -> String {
+        format!("{}", self.plan.display())
+    }
+
+    /// Serializes the plan to DataFusion's protobuf format
+    fn to_proto(&self, py: Python) -> PyResult<PyObject> {
+        let bytes = logical_plan_to_bytes(&self.plan).map_err(DataFusionError::from)?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.plan)
+    }
+}
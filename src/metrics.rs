@@ -0,0 +1,159 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+
+use datafusion::physical_plan::metrics::Metric;
+use datafusion::physical_plan::{displayable, ExecutionPlan};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+/// A single metric recorded by an operator while executing a plan
+#[pyclass(name = "Metric", module = "ballista", subclass)]
+#[derive(Debug, Clone)]
+pub(crate) struct PyMetric {
+    /// Name of the operator, e.g. `ProjectionExec`
+    #[pyo3(get)]
+    operator: String,
+    /// Depth of the operator in the plan, 0 being the root
+    #[pyo3(get)]
+    depth: usize,
+    #[pyo3(get)]
+    name: String,
+    /// Counts are plain numbers, times are in nanoseconds
+    #[pyo3(get)]
+    value: usize,
+    /// The partition the metric was recorded for, if any
+    #[pyo3(get)]
+    partition: Option<usize>,
+    #[pyo3(get)]
+    labels: HashMap<String, String>,
+}
+
+impl PyMetric {
+    fn new(operator: &str, depth: usize, metric: &Metric) -> Self {
+        Self {
+            operator: operator.to_string(),
+            depth,
+            name: metric.value().name().to_string(),
+            value: metric.value().as_usize(),
+            partition: metric.partition(),
+            labels: metric
+                .labels()
+                .iter()
+                .map(|label| (label.name().to_string(), label.value().to_string()))
+                .collect(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyMetric {
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("operator", &self.operator)?;
+        dict.set_item("depth", self.depth)?;
+        dict.set_item("name", &self.name)?;
+        dict.set_item("value", self.value)?;
+        dict.set_item("partition", self.partition)?;
+        dict.set_item("labels", self.labels.clone())?;
+        Ok(dict.into())
+    }
+
+    fn __repr__(&self) -> String {
+        match self.partition {
+            Some(partition) => format!(
+                "Metric({}.{}[{}]={})",
+                self.operator, self.name, partition, self.value
+            ),
+            None => format!("Metric({}.{}={})", self.operator, self.name, self.value),
+        }
+    }
+}
+
+/// The metrics of every operator of an executed plan, from the root down
+#[pyclass(name = "MetricsSet", module = "ballista", subclass)]
+#[derive(Debug, Clone)]
+pub(crate) struct PyMetricsSet {
+    metrics: Vec<PyMetric>,
+}
+
+impl PyMetricsSet {
+    /// Gathers the metrics of `plan` and its children, which only hold values
+    /// once the plan has been executed
+    pub(crate) fn from_plan(plan: &dyn ExecutionPlan) -> Self {
+        let mut metrics = vec![];
+        Self::collect(plan, 0, &mut metrics);
+        Self { metrics }
+    }
+
+    fn collect(plan: &dyn ExecutionPlan, depth: usize, metrics: &mut Vec<PyMetric>) {
+        let display = displayable(plan).one_line().to_string();
+        let operator = display.split(':').next().unwrap_or_default().trim();
+        if let Some(set) = plan.metrics() {
+            metrics.extend(
+                set.iter()
+                    .map(|metric| PyMetric::new(operator, depth, metric.as_ref())),
+            );
+        }
+        for child in plan.children() {
+            Self::collect(child.as_ref(), depth + 1, metrics);
+        }
+    }
+}
+
+#[pymethods]
+impl PyMetricsSet {
+    /// Returns the metrics of the operators named `operator`, or all of them
+    #[args(operator = "None")]
+    fn metrics(&self, operator: Option<&str>) -> Vec<PyMetric> {
+        self.metrics
+            .iter()
+            .filter(|metric| {
+                operator.map_or(true, |operator| metric.operator == operator)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Sums the values of the metrics called `name`, e.g. `output_rows` or
+    /// `elapsed_compute`, over all partitions of the root operator
+    fn root_value(&self, name: &str) -> usize {
+        self.metrics
+            .iter()
+            .filter(|metric| metric.depth == 0 && metric.name == name)
+            .map(|metric| metric.value)
+            .sum()
+    }
+
+    fn to_dicts(&self, py: Python) -> PyResult<Vec<PyObject>> {
+        self.metrics
+            .iter()
+            .map(|metric| metric.to_dict(py))
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.metrics.len()
+    }
+
+    fn __iter__(&self, py: Python) -> PyResult<PyObject> {
+        let metrics =
+            PyList::new(py, self.metrics.iter().cloned().map(|m| m.into_py(py)));
+        Ok(metrics.call_method0("__iter__")?.into())
+    }
+}
@@ -0,0 +1,72 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use datafusion::physical_plan::{displayable, ExecutionPlan};
+use datafusion_proto::bytes::physical_plan_to_bytes;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::errors::DataFusionError;
+
+/// A physical plan that can be inspected from Python
+#[pyclass(name = "ExecutionPlan", module = "ballista", subclass)]
+#[derive(Debug, Clone)]
+pub(crate) struct PyExecutionPlan {
+    plan: Arc<dyn ExecutionPlan>,
+}
+
+impl From<Arc<dyn ExecutionPlan>> for PyExecutionPlan {
+    fn from(plan: Arc<dyn ExecutionPlan>) -> PyExecutionPlan {
+        PyExecutionPlan { plan }
+    }
+}
+
+#[pymethods]
+impl PyExecutionPlan {
+    /// Returns the plans this plan directly depends on
+    fn inputs(&self) -> Vec<PyExecutionPlan> {
+        self.plan.children().into_iter().map(|p| p.into()).collect()
+    }
+
+    /// Returns the plan tree as an indented string, one node per line
+    fn display_indent(&self) -> String {
+        format!("{}", displayable(self.plan.as_ref()).indent())
+    }
+
+    /// Returns this node only, without its children
+    fn display(&self) -> String {
+        format!("{}", displayable(self.plan.as_ref()).one_line())
+    }
+
+    /// Serializes the plan to DataFusion's protobuf format
+    fn to_proto(&self, py: Python) -> PyResult<PyObject> {
+        let bytes =
+            physical_plan_to_bytes(self.plan.clone()).map_err(DataFusionError::from)?;
+        Ok(PyBytes::new(py, &bytes).into())
+    }
+
+    #[getter]
+    fn partition_count(&self) -> usize {
+        self.plan.output_partitioning().partition_count()
+    }
+
+    fn __repr__(&self) -> String {
+        self.display_indent()
+    }
+}
@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use datafusion::arrow::datatypes::Schema;
+use datafusion::arrow::pyarrow::{PyArrowConvert, PyArrowType};
+use datafusion::physical_plan::SendableRecordBatchStream;
+use futures::StreamExt;
+use pyo3::exceptions::PyStopAsyncIteration;
+use pyo3::prelude::*;
+use tokio::runtime::Runtime;
+
+use crate::errors::DataFusionError;
+
+/// A stream of `RecordBatch`es produced by executing a DataFrame.
+/// Batches are only computed when they are pulled from Python, which
+/// allows to consume results that don't fit in memory.
+#[pyclass(name = "RecordBatchStream", module = "ballista", subclass)]
+pub(crate) struct PyRecordBatchStream {
+    stream: SendableRecordBatchStream,
+    // the runtime the plan was started on, it must outlive the stream
+    runtime: Runtime,
+    // streams are not guaranteed to be fused, so they are never polled
+    // again once they returned `None`
+    done: bool,
+}
+
+impl PyRecordBatchStream {
+    pub fn new(stream: SendableRecordBatchStream, runtime: Runtime) -> Self {
+        Self {
+            stream,
+            runtime,
+            done: false,
+        }
+    }
+}
+
+#[pymethods]
+impl PyRecordBatchStream {
+    /// Returns the schema of the batches in this stream
+    fn schema(&self) -> PyArrowType<Schema> {
+        PyArrowType(self.stream.schema().as_ref().clone())
+    }
+
+    /// Returns the next `RecordBatch`, or `None` once the stream is exhausted
+    fn next(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        if self.done {
+            return Ok(None);
+        }
+        let (runtime, stream) = (&self.runtime, &mut self.stream);
+        match py.allow_threads(|| runtime.block_on(stream.next())) {
+            Some(batch) => {
+                let batch = batch.map_err(DataFusionError::from)?;
+                Ok(Some(batch.to_pyarrow(py)?))
+            }
+            None => {
+                self.done = true;
+                Ok(None)
+            }
+        }
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        self.next(py)
+    }
+
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Computes the next batch in the default executor of the running event
+    /// loop, so that awaiting it doesn't block other tasks
+    fn __anext__(slf: PyRef<'_, Self>, py: Python) -> PyResult<Option<PyObject>> {
+        let next = slf.into_py(py).getattr(py, "_anext")?;
+        let future = py
+            .import("asyncio")?
+            .call_method0("get_running_loop")?
+            .call_method1("run_in_executor", (py.None(), next))?;
+        Ok(Some(future.into()))
+    }
+
+    fn _anext(&mut self, py: Python) -> PyResult<PyObject> {
+        self.next(py)?
+            .ok_or_else(|| PyStopAsyncIteration::new_err("stream is exhausted"))
+    }
+}
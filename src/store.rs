@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use object_store::aws::{AmazonS3, AmazonS3Builder};
+use object_store::azure::{MicrosoftAzure, MicrosoftAzureBuilder};
+use object_store::gcp::{GoogleCloudStorage, GoogleCloudStorageBuilder};
+use object_store::local::LocalFileSystem;
+use object_store::ObjectStore;
+
+/// Any of the object stores that can be registered on a context
+#[derive(FromPyObject)]
+pub(crate) enum StorageContexts {
+    AmazonS3(PyAmazonS3Context),
+    GoogleCloudStorage(PyGoogleCloudContext),
+    MicrosoftAzure(PyMicrosoftAzureContext),
+    LocalFileSystem(PyLocalFileSystemContext),
+}
+
+impl StorageContexts {
+    /// Returns the object store together with the host it serves, which is
+    /// the bucket (or container) name for cloud stores.
+    pub fn into_store(self) -> (Arc<dyn ObjectStore>, String) {
+        match self {
+            StorageContexts::AmazonS3(s3) => (s3.inner, s3.bucket_name),
+            StorageContexts::GoogleCloudStorage(gcs) => (gcs.inner, gcs.bucket_name),
+            StorageContexts::MicrosoftAzure(azure) => (azure.inner, azure.container_name),
+            StorageContexts::LocalFileSystem(local) => (local.inner, "".to_string()),
+        }
+    }
+}
+
+#[pyclass(name = "LocalFileSystem", module = "ballista.object_store", subclass)]
+#[derive(Debug, Clone)]
+pub struct PyLocalFileSystemContext {
+    inner: Arc<LocalFileSystem>,
+}
+
+#[pymethods]
+impl PyLocalFileSystemContext {
+    #[args(prefix = "None")]
+    #[new]
+    fn new(prefix: Option<String>) -> PyResult<Self> {
+        let store = match prefix {
+            Some(prefix) => LocalFileSystem::new_with_prefix(prefix)
+                .map_err(|err| PyValueError::new_err(err.to_string()))?,
+            None => LocalFileSystem::new(),
+        };
+        Ok(Self {
+            inner: Arc::new(store),
+        })
+    }
+}
+
+#[pyclass(name = "AmazonS3", module = "ballista.object_store", subclass)]
+#[derive(Debug, Clone)]
+pub struct PyAmazonS3Context {
+    inner: Arc<AmazonS3>,
+    bucket_name: String,
+}
+
+#[pymethods]
+impl PyAmazonS3Context {
+    #[allow(clippy::too_many_arguments)]
+    #[args(
+        region = "None",
+        access_key_id = "None",
+        secret_access_key = "None",
+        endpoint = "None",
+        allow_http = "false"
+    )]
+    #[new]
+    fn new(
+        bucket_name: String,
+        region: Option<String>,
+        access_key_id: Option<String>,
+        secret_access_key: Option<String>,
+        endpoint: Option<String>,
+        allow_http: bool,
+    ) -> PyResult<Self> {
+        let mut builder = AmazonS3Builder::new()
+            .with_bucket_name(&bucket_name)
+            .with_allow_http(allow_http);
+
+        if let Some(region) = region {
+            builder = builder.with_region(region);
+        }
+        if let Some(access_key_id) = access_key_id {
+            builder = builder.with_access_key_id(access_key_id);
+        }
+        if let Some(secret_access_key) = secret_access_key {
+            builder = builder.with_secret_access_key(secret_access_key);
+        }
+        if let Some(endpoint) = endpoint {
+            builder = builder.with_endpoint(endpoint);
+        }
+
+        let store = builder
+            .build()
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(Self {
+            inner: Arc::new(store),
+            bucket_name,
+        })
+    }
+}
+
+#[pyclass(name = "GoogleCloud", module = "ballista.object_store", subclass)]
+#[derive(Debug, Clone)]
+pub struct PyGoogleCloudContext {
+    inner: Arc<GoogleCloudStorage>,
+    bucket_name: String,
+}
+
+#[pymethods]
+impl PyGoogleCloudContext {
+    #[args(service_account_path = "None")]
+    #[new]
+    fn new(bucket_name: String, service_account_path: Option<String>) -> PyResult<Self> {
+        let mut builder = GoogleCloudStorageBuilder::new().with_bucket_name(&bucket_name);
+
+        if let Some(service_account_path) = service_account_path {
+            builder = builder.with_service_account_path(service_account_path);
+        }
+
+        let store = builder
+            .build()
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(Self {
+            inner: Arc::new(store),
+            bucket_name,
+        })
+    }
+}
+
+#[pyclass(name = "MicrosoftAzure", module = "ballista.object_store", subclass)]
+#[derive(Debug, Clone)]
+pub struct PyMicrosoftAzureContext {
+    inner: Arc<MicrosoftAzure>,
+    container_name: String,
+}
+
+#[pymethods]
+impl PyMicrosoftAzureContext {
+    #[args(account = "None", access_key = "None", use_emulator = "false")]
+    #[new]
+    fn new(
+        container_name: String,
+        account: Option<String>,
+        access_key: Option<String>,
+        use_emulator: bool,
+    ) -> PyResult<Self> {
+        let mut builder = MicrosoftAzureBuilder::new()
+            .with_container_name(&container_name)
+            .with_use_emulator(use_emulator);
+
+        if let Some(account) = account {
+            builder = builder.with_account(account);
+        }
+        if let Some(access_key) = access_key {
+            builder = builder.with_access_key(access_key);
+        }
+
+        let store = builder
+            .build()
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+        Ok(Self {
+            inner: Arc::new(store),
+            container_name,
+        })
+    }
+}
+
+pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyAmazonS3Context>()?;
+    m.add_class::<PyMicrosoftAzureContext>()?;
+    m.add_class::<PyGoogleCloudContext>()?;
+    m.add_class::<PyLocalFileSystemContext>()?;
+    Ok(())
+}
@@ -0,0 +1,86 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use pyo3::types::PyBytes;
+use pyo3::{prelude::*, wrap_pyfunction};
+
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::prelude::{DataFrame, SessionContext};
+use datafusion_substrait::logical_plan::{consumer, producer};
+use datafusion_substrait::substrait::proto::Plan;
+use prost::Message;
+
+use crate::context::PySessionContext;
+use crate::dataframe::PyDataFrame;
+use crate::errors::DataFusionError;
+use crate::utils::wait_for_future;
+
+/// Serializes a logical plan to Substrait protobuf bytes
+pub(crate) fn serialize_plan(plan: &LogicalPlan) -> Result<Vec<u8>, DataFusionError> {
+    let plan = producer::to_substrait_plan(plan)?;
+    let mut bytes = Vec::<u8>::new();
+    plan.encode(&mut bytes)
+        .map_err(|err| DataFusionError::Common(err.to_string()))?;
+    Ok(bytes)
+}
+
+/// Plans Substrait protobuf bytes into a logical plan against `ctx`
+fn deserialize_plan(
+    py: Python,
+    ctx: &SessionContext,
+    bytes: &[u8],
+) -> Result<LogicalPlan, DataFusionError> {
+    let plan =
+        Plan::decode(bytes).map_err(|err| DataFusionError::Common(err.to_string()))?;
+    let mut ctx = ctx.clone();
+    let plan = wait_for_future(py, consumer::from_substrait_plan(&mut ctx, &plan))?;
+    Ok(plan)
+}
+
+/// Plans Substrait protobuf bytes into a DataFrame against `ctx`
+pub(crate) fn deserialize_dataframe(
+    py: Python,
+    ctx: &SessionContext,
+    bytes: &[u8],
+) -> Result<DataFrame, DataFusionError> {
+    let plan = deserialize_plan(py, ctx, bytes)?;
+    Ok(DataFrame::new(ctx.state(), plan))
+}
+
+/// Serializes the logical plan of a DataFrame to Substrait protobuf bytes
+#[pyfunction]
+fn to_substrait(df: PyDataFrame, py: Python) -> PyResult<PyObject> {
+    let bytes = serialize_plan(df.dataframe()?.logical_plan())?;
+    Ok(PyBytes::new(py, &bytes).into())
+}
+
+/// Creates a DataFrame from Substrait protobuf bytes
+#[pyfunction]
+fn from_substrait(
+    ctx: PyRef<PySessionContext>,
+    plan: &PyBytes,
+    py: Python,
+) -> PyResult<PyDataFrame> {
+    let df = deserialize_dataframe(py, ctx.context(), plan.as_bytes())?;
+    Ok(PyDataFrame::new(df))
+}
+
+pub(crate) fn init_module(m: &PyModule) -> PyResult<()> {
+    m.add_wrapped(wrap_pyfunction!(to_substrait))?;
+    m.add_wrapped(wrap_pyfunction!(from_substrait))?;
+    Ok(())
+}
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+/// Implements a Datafusion TableProvider that delegates to a python object
+/// implementing `schema()` and `scan(projection, filters, limit)`
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use datafusion::arrow::array::ArrayRef;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::pyarrow::PyArrowType;
+use datafusion::arrow::record_batch::RecordBatch;
+use datafusion::datasource::datasource::TableProviderFilterPushDown;
+use datafusion::datasource::{TableProvider, TableType};
+use datafusion::error::{DataFusionError, Result as DFResult};
+use datafusion::execution::context::SessionState;
+use datafusion::logical_expr::Expr;
+use datafusion::physical_plan::memory::MemoryExec;
+use datafusion::physical_plan::ExecutionPlan;
+
+use crate::expression::PyExpr;
+
+// Wraps a python table provider and implements a Datafusion TableProvider around it
+#[derive(Debug, Clone)]
+pub(crate) struct PyTableProviderAdapter {
+    provider: PyObject,
+    schema: SchemaRef,
+}
+
+impl PyTableProviderAdapter {
+    pub fn new(provider: &PyAny) -> PyResult<Self> {
+        if !provider.hasattr("schema")? || !provider.hasattr("scan")? {
+            return Err(PyValueError::new_err(
+                "provider argument must implement schema() and scan()",
+            ));
+        }
+        let schema = provider
+            .call_method0("schema")?
+            .extract::<PyArrowType<_>>()?
+            .0;
+        Ok(Self {
+            provider: provider.into(),
+            schema: Arc::new(schema),
+        })
+    }
+
+    /// Calls the provider's `scan` and collects the batches it returns,
+    /// either as a `pyarrow.Table` or as an iterable of `RecordBatch`es
+    fn scan_batches(
+        &self,
+        py: Python,
+        columns: Option<Vec<String>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> PyResult<Vec<RecordBatch>> {
+        let filters: Vec<PyExpr> = filters.iter().cloned().map(PyExpr::from).collect();
+        let result = self
+            .provider
+            .as_ref(py)
+            .call_method1("scan", (columns, filters.into_py(py), limit))?;
+
+        let result = if result.hasattr("to_batches")? {
+            result.call_method0("to_batches")?
+        } else {
+            result
+        };
+        result
+            .iter()?
+            .map(|batch| -> PyResult<RecordBatch> {
+                Ok(batch?.extract::<PyArrowType<_>>()?.0)
+            })
+            .collect()
+    }
+}
+
+/// Picks the columns of `schema` out of `batch` by name, as providers may
+/// ignore the requested columns and return full-width batches
+fn project_batch(batch: &RecordBatch, schema: &SchemaRef) -> DFResult<RecordBatch> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| -> DFResult<ArrayRef> {
+            let index = batch.schema().index_of(field.name())?;
+            Ok(batch.column(index).clone())
+        })
+        .collect::<DFResult<Vec<_>>>()?;
+    Ok(RecordBatch::try_new(schema.clone(), columns)?)
+}
+
+#[async_trait]
+impl TableProvider for PyTableProviderAdapter {
+    /// Returns the table provider as [`Any`](std::any::Any) so that it can be
+    /// downcast to a specific implementation.
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Get a reference to the schema for this table
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Get the type of this table for metadata/catalog purposes.
+    fn table_type(&self) -> TableType {
+        TableType::Base
+    }
+
+    /// Create an ExecutionPlan that will scan the table.
+    /// The python provider is called right away and the batches it returns
+    /// are held in memory until the plan is executed.
+    async fn scan(
+        &self,
+        _ctx: &SessionState,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> DFResult<Arc<dyn ExecutionPlan>> {
+        let schema = match projection {
+            Some(projection) => Arc::new(self.schema.project(projection)?),
+            None => self.schema.clone(),
+        };
+        let columns = projection.map(|_| {
+            schema
+                .fields()
+                .iter()
+                .map(|field| field.name().to_owned())
+                .collect::<Vec<_>>()
+        });
+
+        let batches =
+            Python::with_gil(|py| self.scan_batches(py, columns, filters, limit))
+                .map_err(|err| DataFusionError::External(Box::new(err)))?;
+        let batches = batches
+            .iter()
+            .map(|batch| project_batch(batch, &schema))
+            .collect::<DFResult<Vec<_>>>()?;
+
+        Ok(Arc::new(MemoryExec::try_new(&[batches], schema, None)?))
+    }
+
+    /// Tests whether the table provider can make use of a filter expression
+    /// to optimise data retrieval. Filters are pushed down to providers that
+    /// accept them in `supports_filter_pushdown`, but they are still
+    /// evaluated by DataFusion afterwards.
+    fn supports_filter_pushdown(
+        &self,
+        filter: &Expr,
+    ) -> DFResult<TableProviderFilterPushDown> {
+        Python::with_gil(|py| -> PyResult<TableProviderFilterPushDown> {
+            let provider = self.provider.as_ref(py);
+            if !provider.hasattr("supports_filter_pushdown")? {
+                return Ok(TableProviderFilterPushDown::Unsupported);
+            }
+            let filter = PyExpr::from(filter.clone()).into_py(py);
+            let supported: bool = provider
+                .call_method1("supports_filter_pushdown", (filter,))?
+                .extract()?;
+            Ok(if supported {
+                TableProviderFilterPushDown::Inexact
+            } else {
+                TableProviderFilterPushDown::Unsupported
+            })
+        })
+        .map_err(|err| DataFusionError::External(Box::new(err)))
+    }
+}
@@ -118,7 +118,7 @@ pub struct PyAggregateUDF {
 #[pymethods]
 impl PyAggregateUDF {
     #[new(name, accumulator, input_type, return_type, state_type, volatility)]
-    fn new(
+    pub(crate) fn new(
         name: &str,
         accumulator: PyObject,
         input_type: PyArrowType<DataType>,
@@ -137,6 +137,12 @@ impl PyAggregateUDF {
         Ok(Self { function })
     }
 
+    /// the name under which the udaf is registered
+    #[getter]
+    fn name(&self) -> String {
+        self.function.name.clone()
+    }
+
     /// creates a new PyExpr with the call of the udf
     #[args(args = "*")]
     fn __call__(&self, args: Vec<PyExpr>) -> PyResult<PyExpr> {
@@ -69,7 +69,7 @@ pub struct PyScalarUDF {
 #[pymethods]
 impl PyScalarUDF {
     #[new(name, func, input_types, return_type, volatility)]
-    fn new(
+    pub(crate) fn new(
         name: &str,
         func: PyObject,
         input_types: PyArrowType<Vec<DataType>>,
@@ -86,6 +86,12 @@ impl PyScalarUDF {
         Ok(Self { function })
     }
 
+    /// the name under which the udf is registered
+    #[getter]
+    fn name(&self) -> String {
+        self.function.name.clone()
+    }
+
     /// creates a new PyExpr with the call of the udf
     #[args(args = "*")]
     fn __call__(&self, args: Vec<PyExpr>) -> PyResult<PyExpr> {
@@ -15,10 +15,23 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::datatype::PyDataType;
 use crate::errors::DataFusionError;
+use datafusion::arrow::datatypes::IntervalMonthDayNanoType;
+use datafusion::arrow::datatypes::{DataType, Schema};
+use datafusion::arrow::pyarrow::PyArrowType;
 use datafusion::logical_expr::Volatility;
+use datafusion::parquet::basic::Compression;
+use datafusion::prelude::{
+    AvroReadOptions, CsvReadOptions, NdJsonReadOptions, ParquetReadOptions,
+};
+use datafusion::scalar::ScalarValue;
+use pyo3::exceptions::PyValueError;
+use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
+use pyo3::types::PyType;
 use std::future::Future;
+use std::sync::Arc;
 use tokio::runtime::Runtime;
 
 /// Utility to collect rust futures with GIL released
@@ -45,3 +58,147 @@ pub(crate) fn parse_volatility(value: &str) -> Result<Volatility, DataFusionErro
         }
     })
 }
+
+pub(crate) fn parse_compression(value: &str) -> Result<Compression, DataFusionError> {
+    Ok(match value.to_lowercase().as_str() {
+        "uncompressed" => Compression::UNCOMPRESSED,
+        "snappy" => Compression::SNAPPY,
+        "gzip" => Compression::GZIP,
+        "lzo" => Compression::LZO,
+        "brotli" => Compression::BROTLI,
+        "lz4" => Compression::LZ4,
+        "zstd" => Compression::ZSTD,
+        value => {
+            return Err(DataFusionError::Common(format!(
+                "Unsupported compression type: `{}`, supported values are: \
+                 uncompressed, snappy, gzip, lzo, brotli, lz4 and zstd.",
+                value
+            )))
+        }
+    })
+}
+
+/// The python types `py_obj_to_scalar_value` dispatches on, imported once
+struct ScalarTypes {
+    scalar_type: Py<PyType>,
+    scalar: PyObject,
+    timedelta_type: Py<PyType>,
+}
+
+static SCALAR_TYPES: GILOnceCell<ScalarTypes> = GILOnceCell::new();
+
+fn scalar_types(py: Python) -> PyResult<&ScalarTypes> {
+    if let Some(types) = SCALAR_TYPES.get(py) {
+        return Ok(types);
+    }
+    let pa = PyModule::import(py, "pyarrow")?;
+    let types = ScalarTypes {
+        scalar_type: pa.getattr("Scalar")?.downcast::<PyType>()?.into(),
+        scalar: pa.getattr("scalar")?.into(),
+        timedelta_type: PyModule::import(py, "datetime")?
+            .getattr("timedelta")?
+            .downcast::<PyType>()?
+            .into(),
+    };
+    Ok(SCALAR_TYPES.get_or_init(py, || types))
+}
+
+/// Converts a python value to a `ScalarValue`, going through `pyarrow.scalar`
+/// for values that aren't pyarrow scalars already. `datetime.timedelta` is
+/// mapped to a month-day-nano interval, as durations can't be used as
+/// scalars yet.
+pub(crate) fn py_obj_to_scalar_value(py: Python, value: &PyAny) -> PyResult<ScalarValue> {
+    let types = scalar_types(py)?;
+    if value.is_instance(types.scalar_type.as_ref(py))? {
+        value.extract()
+    } else if value.is_instance(types.timedelta_type.as_ref(py))? {
+        let days: i32 = value.getattr("days")?.extract()?;
+        let seconds: i64 = value.getattr("seconds")?.extract()?;
+        let microseconds: i64 = value.getattr("microseconds")?.extract()?;
+        let nanos = (seconds * 1_000_000 + microseconds) * 1_000;
+        Ok(ScalarValue::IntervalMonthDayNano(Some(
+            IntervalMonthDayNanoType::make_value(0, days, nanos),
+        )))
+    } else {
+        types.scalar.as_ref(py).call1((value,))?.extract()
+    }
+}
+
+fn convert_table_partition_cols(
+    table_partition_cols: Vec<(String, PyDataType)>,
+) -> Vec<(String, DataType)> {
+    table_partition_cols
+        .iter()
+        .map(|(name, t)| (name.clone(), t.data_type.clone()))
+        .collect()
+}
+
+/// Builds the options to read CSV files, validating that `delimiter` is a
+/// single character
+pub(crate) fn csv_read_options<'a>(
+    schema: Option<&'a Schema>,
+    has_header: bool,
+    delimiter: &str,
+    schema_infer_max_records: usize,
+    file_extension: &'a str,
+    table_partition_cols: Vec<(String, PyDataType)>,
+) -> PyResult<CsvReadOptions<'a>> {
+    let delimiter = delimiter.as_bytes();
+    if delimiter.len() != 1 {
+        return Err(PyValueError::new_err(
+            "Delimiter must be a single character",
+        ));
+    }
+
+    let mut options = CsvReadOptions::new()
+        .has_header(has_header)
+        .delimiter(delimiter[0])
+        .schema_infer_max_records(schema_infer_max_records)
+        .file_extension(file_extension)
+        .table_partition_cols(convert_table_partition_cols(table_partition_cols));
+    options.schema = schema;
+    Ok(options)
+}
+
+/// Builds the options to read parquet files
+pub(crate) fn parquet_read_options(
+    table_partition_cols: Vec<(String, PyDataType)>,
+    parquet_pruning: bool,
+    file_extension: &str,
+    skip_metadata: bool,
+) -> ParquetReadOptions<'_> {
+    let mut options = ParquetReadOptions::default()
+        .table_partition_cols(convert_table_partition_cols(table_partition_cols))
+        .parquet_pruning(parquet_pruning)
+        .skip_metadata(skip_metadata);
+    options.file_extension = file_extension;
+    options
+}
+
+/// Builds the options to read newline-delimited JSON files
+pub(crate) fn json_read_options(
+    schema: Option<PyArrowType<Schema>>,
+    schema_infer_max_records: usize,
+    file_extension: &str,
+    table_partition_cols: Vec<(String, PyDataType)>,
+) -> NdJsonReadOptions<'_> {
+    let mut options = NdJsonReadOptions::default()
+        .table_partition_cols(convert_table_partition_cols(table_partition_cols));
+    options.schema = schema.map(|schema| Arc::new(schema.0));
+    options.schema_infer_max_records = schema_infer_max_records;
+    options.file_extension = file_extension;
+    options
+}
+
+/// Builds the options to read Avro files
+pub(crate) fn avro_read_options(
+    schema: Option<PyArrowType<Schema>>,
+    file_extension: &str,
+    table_partition_cols: Vec<(String, PyDataType)>,
+) -> AvroReadOptions<'_> {
+    let mut options = AvroReadOptions::default()
+        .table_partition_cols(convert_table_partition_cols(table_partition_cols));
+    options.schema = schema.map(|schema| Arc::new(schema.0));
+    options.file_extension = file_extension;
+    options
+}
@@ -0,0 +1,165 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use datafusion::scalar::ScalarValue;
+use datafusion_expr::window_frame::{WindowFrame, WindowFrameBound, WindowFrameUnits};
+use pyo3::prelude::*;
+
+use crate::errors::DataFusionError;
+
+/// The frame of a window function, i.e. the rows that are aggregated for
+/// every row of the partition.
+#[pyclass(name = "WindowFrame", module = "ballista", subclass)]
+#[derive(Clone)]
+pub(crate) struct PyWindowFrame {
+    pub(crate) frame: WindowFrame,
+}
+
+impl From<PyWindowFrame> for WindowFrame {
+    fn from(frame: PyWindowFrame) -> WindowFrame {
+        frame.frame
+    }
+}
+
+/// A bound of a window frame, either a plain offset (`None` being
+/// unbounded and `0` the current row) whose direction depends on the side
+/// of the frame, or an explicit `(kind, offset)` pair where `kind` is one of
+/// `preceding`, `current_row` and `following`.
+#[derive(FromPyObject)]
+enum PyWindowFrameBound {
+    Explicit((String, Option<u64>)),
+    Offset(Option<u64>),
+}
+
+impl PyWindowFrameBound {
+    fn into_bound(self, is_start: bool) -> Result<WindowFrameBound, DataFusionError> {
+        let bound = match self {
+            PyWindowFrameBound::Offset(Some(0)) => WindowFrameBound::CurrentRow,
+            PyWindowFrameBound::Offset(offset) if is_start => {
+                WindowFrameBound::Preceding(ScalarValue::UInt64(offset))
+            }
+            PyWindowFrameBound::Offset(offset) => {
+                WindowFrameBound::Following(ScalarValue::UInt64(offset))
+            }
+            PyWindowFrameBound::Explicit((kind, offset)) => {
+                match (kind.to_lowercase().as_str(), offset) {
+                    ("preceding", offset) => {
+                        WindowFrameBound::Preceding(ScalarValue::UInt64(offset))
+                    }
+                    ("following", offset) => {
+                        WindowFrameBound::Following(ScalarValue::UInt64(offset))
+                    }
+                    ("current_row", None | Some(0)) => WindowFrameBound::CurrentRow,
+                    ("current_row", Some(_)) => {
+                        return Err(DataFusionError::Common(
+                            "The current row bound doesn't take an offset".to_owned(),
+                        ))
+                    }
+                    (kind, _) => {
+                        return Err(DataFusionError::Common(format!(
+                            "Unsupported window frame bound: `{}`, supported values \
+                             are: preceding, current_row and following.",
+                            kind
+                        )))
+                    }
+                }
+            }
+        };
+        Ok(bound)
+    }
+}
+
+/// Orders the bounds along the partition, `None` standing for unbounded
+fn bound_position(bound: &WindowFrameBound) -> Option<i128> {
+    match bound {
+        WindowFrameBound::Preceding(ScalarValue::UInt64(offset)) => {
+            offset.map(|offset| -(offset as i128))
+        }
+        WindowFrameBound::Following(ScalarValue::UInt64(offset)) => {
+            offset.map(|offset| offset as i128)
+        }
+        _ => Some(0),
+    }
+}
+
+/// Rejects the frames that SQL doesn't allow, mirroring the checks DataFusion
+/// applies to frames parsed from SQL
+fn validate(frame: &WindowFrame) -> Result<(), DataFusionError> {
+    if let WindowFrameBound::Following(ScalarValue::UInt64(None)) = frame.start_bound {
+        return Err(DataFusionError::Common(
+            "Invalid window frame: start bound cannot be unbounded following".to_owned(),
+        ));
+    }
+    if let WindowFrameBound::Preceding(ScalarValue::UInt64(None)) = frame.end_bound {
+        return Err(DataFusionError::Common(
+            "Invalid window frame: end bound cannot be unbounded preceding".to_owned(),
+        ));
+    }
+    let start = bound_position(&frame.start_bound).unwrap_or(i128::MIN);
+    let end = bound_position(&frame.end_bound).unwrap_or(i128::MAX);
+    if start > end {
+        return Err(DataFusionError::Common(format!(
+            "Invalid window frame: {} cannot come after {}",
+            frame.start_bound, frame.end_bound
+        )));
+    }
+    Ok(())
+}
+
+#[pymethods]
+impl PyWindowFrame {
+    /// Creates a frame of the given `units` (rows, range or groups) between
+    /// `start_bound` and `end_bound`.
+    /// A plain bound is a number of preceding rows (or values, or groups)
+    /// for the start and of following ones for the end, with `None` being
+    /// unbounded and `0` the current row, e.g. `WindowFrame("rows", 2, 0)` is
+    /// `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW`. Bounds can also be given
+    /// as `(kind, offset)` pairs, e.g.
+    /// `WindowFrame("rows", ("preceding", 3), ("preceding", 1))` is
+    /// `ROWS BETWEEN 3 PRECEDING AND 1 PRECEDING`.
+    #[new(units, start_bound, end_bound)]
+    fn new(
+        units: &str,
+        start_bound: PyWindowFrameBound,
+        end_bound: PyWindowFrameBound,
+    ) -> PyResult<Self> {
+        let units = match units.to_lowercase().as_str() {
+            "rows" => WindowFrameUnits::Rows,
+            "range" => WindowFrameUnits::Range,
+            "groups" => WindowFrameUnits::Groups,
+            units => {
+                return Err(DataFusionError::Common(format!(
+                    "Unsupported window frame units: `{}`, supported values are: \
+                     rows, range and groups.",
+                    units
+                ))
+                .into())
+            }
+        };
+        let frame = WindowFrame {
+            units,
+            start_bound: start_bound.into_bound(true)?,
+            end_bound: end_bound.into_bound(false)?,
+        };
+        validate(&frame)?;
+        Ok(Self { frame })
+    }
+
+    fn __str__(&self) -> String {
+        format!("{}", self.frame)
+    }
+}